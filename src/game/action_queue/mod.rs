@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+use crate::game::gamestate::{AppState, PauseState};
+use crate::ui::tween::TweenSet;
+
+pub mod resource;
+pub mod system;
+
+use resource::ActionQueue;
+use system::{run_action_queue, clear_action_queue};
+
+pub struct ActionQueuePlugin;
+
+impl Plugin for ActionQueuePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ActionQueue>()
+        .add_systems(OnEnter(AppState::Setup), clear_action_queue)
+        // after the animations of this frame have moved on (and maybe finished)
+        .add_systems(Update, run_action_queue.after(TweenSet).run_if(in_state(AppState::PlayerTurn)).run_if(in_state(PauseState::Running)));
+    }
+}
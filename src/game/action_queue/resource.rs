@@ -0,0 +1,25 @@
+use std::collections::VecDeque;
+use bevy::prelude::*;
+
+// game actions that wait for the card animations to finish
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum QueuedAction {
+    ChangeTurn, // after the discard lands on the graveyard
+    EndRound(Entity), // player who called the end of the round
+}
+
+#[derive(Resource, Default)]
+pub struct ActionQueue {
+    pub actions: VecDeque<QueuedAction>,
+}
+
+impl ActionQueue {
+    pub fn push(&mut self, action: QueuedAction) {
+        info!(target: "mygame", "Action queued: {:?}", action);
+        self.actions.push_back(action);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+}
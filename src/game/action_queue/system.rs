@@ -0,0 +1,70 @@
+use bevy::prelude::*;
+use crate::game::action_queue::resource::{ActionQueue, QueuedAction};
+use crate::game::{card::component::Card, gamestate::AppState, player::component::Player, turn_player::component::Turn};
+use crate::ui::card_animation::component::CardAnimation;
+use crate::ui::deck_shuffle::component::ShuffleIntro;
+
+// true while a card is flying: being dealt, moved or flipped
+fn cards_animating(
+    animation_query: &Query<(), With<CardAnimation>>,
+    card_query: &Query<&Card>,
+) -> bool {
+    !animation_query.is_empty() || card_query.iter().any(|card| card.is_being_dealt)
+}
+
+// run condition: no animation running, no shuffle intro and nothing waiting, the board accepts input
+pub fn board_idle(
+    animation_query: Query<(), With<CardAnimation>>,
+    card_query: Query<&Card>,
+    queue: Res<ActionQueue>,
+    intro: Option<Res<ShuffleIntro>>,
+) -> bool {
+    intro.is_none() && queue.is_empty() && !cards_animating(&animation_query, &card_query)
+}
+
+// run the queued actions once the cards are in their place
+pub fn run_action_queue(
+    mut queue: ResMut<ActionQueue>,
+    animation_query: Query<(), With<CardAnimation>>,
+    card_query: Query<&Card>,
+    turn: Option<ResMut<Turn>>,
+    player_query: Query<Entity, With<Player>>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    if queue.is_empty() || cards_animating(&animation_query, &card_query) {
+        return;
+    }
+    let Some(mut turn) = turn else { return; };
+
+    while let Some(action) = queue.actions.pop_front() {
+        match action {
+            QueuedAction::ChangeTurn => change_turn(&mut turn, &player_query),
+            QueuedAction::EndRound(caller) => {
+                turn.round_caller = Some(caller);
+                next_state.set(AppState::RoundEnd);
+                info!(target: "mygame", "Round ended by player!");
+                // nothing else happens in this round
+                queue.actions.clear();
+            }
+        }
+    }
+}
+
+// next player in order
+fn change_turn(turn: &mut Turn, player_query: &Query<Entity, With<Player>>) {
+    let players: Vec<Entity> = player_query.iter().collect();
+
+    if let Some(pos) = players.iter().position(|&p| p == turn.current_player) {
+        let next_index = (pos + 1) % players.len();
+        turn.current_player = players[next_index];
+        turn.has_drawn_card = false;
+        turn.number += 1;
+
+        info!(target: "mygame", "Turn automatically changed to player: {:?}", turn.current_player);
+    }
+}
+
+// a new round (or match) starts with nothing pending
+pub fn clear_action_queue(mut queue: ResMut<ActionQueue>) {
+    queue.actions.clear();
+}
@@ -1,4 +1,10 @@
-use bevy::{ecs::entity::Entity, prelude::{Component, Resource, Handle, Image, Vec3}};
+use std::collections::HashMap;
+use bevy::{ecs::entity::Entity, prelude::{Component, Resource, Handle, Image, TextureAtlas, Vec2, Vec3}};
+use serde::{Deserialize, Serialize};
+use crate::game::deck_theme::resource::TextureFilter;
+
+// size of the card textures (before scale)
+pub const CARD_SIZE: Vec2 = Vec2::new(80.0, 120.0);
 
 // COMPONENTS
 #[derive(Component)]
@@ -8,7 +14,7 @@ pub struct Card {
     pub owner_id: Option<Entity>,
     pub position: CardPosition,
     pub suit: Suit,
-    pub front_face: Handle<Image>,
+    pub front_face: CardFront,
     pub from_deck: bool,
     pub is_being_dealt: bool,
 }
@@ -16,6 +22,18 @@ pub struct Card {
 #[derive(Component)]
 pub struct Selected;
 
+// value/suit text drawn over the card, child of the card (text faces and high contrast)
+#[derive(Component)]
+pub struct CardLabel;
+
+// shape and color of the suit in the corner of the card (colorblind setting)
+#[derive(Component)]
+pub struct SuitMarker;
+
+// marks where the dragged card would be dropped
+#[derive(Component)]
+pub struct DropZoneHighlight;
+
 #[derive(Component)]
 pub struct PreviousCardPosition(pub CardPosition);
 
@@ -34,21 +52,99 @@ pub enum CardPosition {
     DrawnCard(Entity)
 }
 
-#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub enum Suit {
+    // spanish deck
     Coarse,
     Cup,
     Gold,
     Sword,
+    // french deck
+    Hearts,
+    Diamonds,
+    Clubs,
+    Spades,
+    // any deck, value 0
+    Joker,
+}
+
+impl Suit {
+    // name in theme paths ("{suit}/{value}.PNG") and string tables
+    pub fn key(&self) -> &'static str {
+        match self {
+            Suit::Coarse => "coarse",
+            Suit::Cup => "cup",
+            Suit::Gold => "gold",
+            Suit::Sword => "sword",
+            Suit::Hearts => "hearts",
+            Suit::Diamonds => "diamonds",
+            Suit::Clubs => "clubs",
+            Suit::Spades => "spades",
+            Suit::Joker => "joker",
+        }
+    }
+
+    // row of the suit in a theme atlas, jokers after the four suits
+    pub fn index(&self) -> usize {
+        match self {
+            Suit::Coarse | Suit::Hearts => 0,
+            Suit::Cup | Suit::Diamonds => 1,
+            Suit::Gold | Suit::Clubs => 2,
+            Suit::Sword | Suit::Spades => 3,
+            Suit::Joker => 4,
+        }
+    }
+
+    // deck the suit belongs to, for card names ("card.value.<deck>.<value>")
+    pub fn deck_key(&self) -> &'static str {
+        match self {
+            Suit::Coarse | Suit::Cup | Suit::Gold | Suit::Sword => "spanish",
+            Suit::Hearts | Suit::Diamonds | Suit::Clubs | Suit::Spades => "french",
+            Suit::Joker => "joker",
+        }
+    }
+
+    pub fn is_red(&self) -> bool {
+        matches!(self, Suit::Hearts | Suit::Diamonds | Suit::Cup | Suit::Joker)
+    }
+}
+
+// front image of a card, a region of it when the theme uses an atlas
+#[derive(Clone, Default)]
+pub struct CardFront {
+    pub image: Handle<Image>,
+    pub atlas: Option<TextureAtlas>,
+    pub label: bool, // no art for this card: blank front, its CardLabel shows the value
 }
 
 // RESOURCES
+// card art of the selected deck theme
 #[derive(Resource)]
-pub struct CardHandles(pub Vec<Handle<Image>>);
+pub struct CardHandles {
+    pub theme: String, // id of the theme, empty for text faces
+    pub fronts: HashMap<(Suit, u8), CardFront>,
+    pub filter: TextureFilter,
+    pub card_size: Option<Vec2>,
+}
+
+impl CardHandles {
+    pub fn front(&self, suit: Suit, value: u8) -> CardFront {
+        self.fronts.get(&(suit, value)).cloned().unwrap_or_default()
+    }
+}
 
 #[derive(Resource)]
 pub struct CardBack(pub Handle<Image>);
 
+// drawn card being dragged with the mouse
+#[derive(Resource, Default)]
+pub struct CardDrag {
+    pub entity: Option<Entity>,
+    pub origin: Vec3, // translation before the drag, used to snap back
+    pub press_pos: Vec2, // cursor world position when pressed
+    pub moved: bool, // false while it is only a click
+}
+
 #[derive(Resource)]
 pub struct DoubleClick {
     pub last_card: Option<Entity>,
@@ -0,0 +1,235 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use crate::game::card::component::{Card, CardPosition, CardDrag, DropZoneHighlight, Selected, CARD_SIZE};
+use bevy::picking::hover::HoverMap;
+use crate::game::card::system::hovered_entities;
+use crate::game::card::utils::{card_swap, discard_card};
+use crate::game::action_queue::resource::ActionQueue;
+use crate::game::{graveyard::component::Graveyard, turn_player::component::Turn, hand::component::Hand, player::component::Player};
+use crate::game::{gamestate::GameEntity, special_cards::resource::SpecialCardEffect};
+use crate::global::settings::resource::Settings;
+use crate::game::table::resource::TableLayout;
+use crate::ui::card_animation::component::{CardAnimation, movement_animation};
+use crate::ui::tween::component::Ease;
+
+const DRAG_Z: f32 = 200.0;
+const DRAG_SCALE: f32 = 1.1;
+const DRAG_THRESHOLD: f32 = 5.0; // pixels before a press counts as a drag
+const DROP_ZONE_COLOR: Color = Color::srgba(0.3, 0.9, 0.4, 0.6);
+
+// where the dragged card would land if released now
+enum DropZone {
+    Graveyard,
+    HandCard(Entity),
+}
+
+fn cursor_world_pos(
+    windows: &Query<&Window, With<PrimaryWindow>>,
+    camera_query: &Query<(&Camera, &GlobalTransform)>,
+) -> Option<Vec2> {
+    let window = windows.single().ok()?;
+    let (camera, camera_transform) = camera_query.single().ok()?;
+    let cursor_pos = window.cursor_position()?;
+    camera.viewport_to_world_2d(camera_transform, cursor_pos).ok()
+}
+
+// the dragged card ignores picking, so the hovered entity is what lies below it
+fn detect_drop_zone(
+    hover_map: &HoverMap,
+    current_player: Entity,
+    card_query: &Query<(Entity, &mut Transform, &mut Card), With<Card>>,
+    graveyard_query: &Query<&mut Graveyard>,
+) -> Option<DropZone> {
+    let hovered = hovered_entities(hover_map).into_iter()
+        .find(|entity| card_query.contains(*entity) || graveyard_query.contains(*entity))?;
+
+    if graveyard_query.contains(hovered) {
+        return Some(DropZone::Graveyard);
+    }
+    match card_query.get(hovered).ok()?.2.position {
+        CardPosition::Graveyard => Some(DropZone::Graveyard),
+        CardPosition::Hand(owner) if owner == current_player => Some(DropZone::HandCard(hovered)),
+        _ => None,
+    }
+}
+
+// press on the drawn card starts the drag (before card_selection so the click is not handled twice)
+#[allow(clippy::too_many_arguments)]
+pub fn start_card_drag(
+    mut commands: Commands,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+    hover_map: Res<HoverMap>,
+    card_query: Query<(&Transform, &Card), Without<CardAnimation>>,
+    turn: Res<Turn>,
+    settings: Res<Settings>,
+    special_effect: Option<Res<SpecialCardEffect>>,
+    mut drag: ResMut<CardDrag>,
+) {
+    if !settings.drag_and_drop || drag.entity.is_some() || !mouse_input.just_pressed(MouseButton::Left) {
+        return;
+    }
+
+    // targeting a special effect uses clicks
+    if let Some(effect) = special_effect
+        && (effect.awaiting_target || effect.awaiting_own_card) {
+        return;
+    }
+
+    let Some(world_pos) = cursor_world_pos(&windows, &camera_query) else { return; };
+
+    let drawn_card = hovered_entities(&hover_map).into_iter()
+        .find(|entity| card_query.contains(*entity))
+        .and_then(|entity| card_query.get(entity).ok().map(|(transform, card)| (entity, transform, card)))
+        .filter(|(_, _, card)| card.position == CardPosition::DrawnCard(turn.current_player) && !card.is_being_dealt);
+
+    if let Some((entity, transform, _)) = drawn_card {
+        // let the picking see what is below the dragged card
+        commands.entity(entity).insert(Pickable::IGNORE);
+        *drag = CardDrag {
+            entity: Some(entity),
+            origin: transform.translation,
+            press_pos: world_pos,
+            moved: false,
+        };
+    }
+}
+
+// dragged card follows the cursor and the drop zone below is highlighted
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn update_card_drag(
+    mut commands: Commands,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+    hover_map: Res<HoverMap>,
+    mut card_query: Query<(Entity, &mut Transform, &mut Card), With<Card>>,
+    graveyard_query: Query<&mut Graveyard>,
+    transform_query: Query<&Transform, (With<Graveyard>, Without<Card>)>,
+    mut highlight_query: Query<(&mut Transform, &mut Visibility), (With<DropZoneHighlight>, Without<Card>, Without<Graveyard>)>,
+    turn: Res<Turn>,
+    layout: Res<TableLayout>,
+    mut drag: ResMut<CardDrag>,
+) {
+    let Ok((mut highlight_transform, mut visibility)) = highlight_query.single_mut() else {
+        // spawn once per game
+        if drag.entity.is_some() {
+            commands.spawn((
+                Sprite::from_color(DROP_ZONE_COLOR, CARD_SIZE + Vec2::splat(10.0)),
+                Transform::default(),
+                Visibility::Hidden,
+                Pickable::IGNORE, // larger than the card, must not take its clicks
+                DropZoneHighlight,
+                GameEntity,
+            ));
+        }
+        return;
+    };
+
+    let Some(dragged) = drag.entity else {
+        *visibility = Visibility::Hidden;
+        return;
+    };
+    let Some(world_pos) = cursor_world_pos(&windows, &camera_query) else { return; };
+
+    if !drag.moved && world_pos.distance(drag.press_pos) > DRAG_THRESHOLD {
+        drag.moved = true;
+    }
+    if !drag.moved {
+        return;
+    }
+
+    // highlight the zone below the cursor
+    let zone_transform = match detect_drop_zone(&hover_map, turn.current_player, &card_query, &graveyard_query) {
+        Some(DropZone::Graveyard) => transform_query.single().ok().copied(),
+        Some(DropZone::HandCard(entity)) => card_query.get(entity).ok().map(|(_, transform, _)| *transform),
+        None => None,
+    };
+    match zone_transform {
+        Some(zone) => {
+            highlight_transform.translation = zone.translation.with_z(DRAG_Z - 1.0);
+            highlight_transform.rotation = zone.rotation;
+            highlight_transform.scale = zone.scale;
+            *visibility = Visibility::Visible;
+        }
+        None => *visibility = Visibility::Hidden,
+    }
+
+    // lift the card and follow the cursor
+    if let Ok((_, mut transform, _)) = card_query.get_mut(dragged) {
+        transform.translation = world_pos.extend(DRAG_Z);
+        transform.rotation = Quat::IDENTITY;
+        transform.scale = layout.card_scale() * DRAG_SCALE;
+    }
+}
+
+// release: discard on the graveyard, swap on a hand card or snap back
+#[allow(clippy::too_many_arguments)]
+pub fn end_card_drag(
+    mut commands: Commands,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    hover_map: Res<HoverMap>,
+    mut card_query: Query<(Entity, &mut Transform, &mut Card), With<Card>>,
+    selected_query: Query<Entity, With<Selected>>,
+    turn: ResMut<Turn>,
+    mut hand_query: Query<&mut Hand>,
+    mut graveyard_query: Query<&mut Graveyard>,
+    player_query: Query<(Entity, &Player)>,
+    layout: Res<TableLayout>,
+    mut drag: ResMut<CardDrag>,
+    mut queue: ResMut<ActionQueue>,
+) {
+    let Some(dragged) = drag.entity else { return; };
+    if !mouse_input.just_released(MouseButton::Left) {
+        return;
+    }
+    let drag = std::mem::take(&mut *drag);
+
+    // restore the size and picking before leaving the card in its new place
+    commands.entity(dragged).insert(Pickable::default());
+    if let Ok((_, mut transform, _)) = card_query.get_mut(dragged) {
+        transform.scale = layout.card_scale();
+    }
+
+    // a press without movement works like a click on the drawn card
+    let zone = if drag.moved {
+        detect_drop_zone(&hover_map, turn.current_player, &card_query, &graveyard_query)
+    } else {
+        Some(DropZone::Graveyard)
+    };
+
+    match zone {
+        Some(DropZone::Graveyard) => {
+            discard_card(dragged, &mut card_query, &mut graveyard_query, turn, &mut queue, &mut commands, &selected_query);
+        }
+        Some(DropZone::HandCard(hand_card)) => {
+            card_swap(hand_card, &mut card_query, &mut graveyard_query, turn, &mut hand_query, &player_query, &mut queue, &layout, &mut commands, &selected_query);
+        }
+        None => {
+            info!(target: "mygame", "Invalid drop, card returns to its place");
+            let Ok((_, transform, _)) = card_query.get(dragged) else { return; };
+            commands.entity(dragged).insert(movement_animation(transform.translation, drag.origin, 0.3, Ease::BackOut));
+        }
+    }
+}
+
+// drop any drag when the game pauses or the turn phase ends: card back in place and pickable
+pub fn reset_drag(
+    mut commands: Commands,
+    mut card_query: Query<&mut Transform, With<Card>>,
+    mut highlight_query: Query<&mut Visibility, With<DropZoneHighlight>>,
+    layout: Res<TableLayout>,
+    mut drag: ResMut<CardDrag>,
+) {
+    let drag = std::mem::take(&mut *drag);
+    let Some(dragged) = drag.entity else { return; };
+
+    if let Ok(mut transform) = card_query.get_mut(dragged) {
+        transform.translation = drag.origin;
+        transform.scale = layout.card_scale();
+        commands.entity(dragged).try_insert(Pickable::default());
+    }
+    if let Ok(mut visibility) = highlight_query.single_mut() {
+        *visibility = Visibility::Hidden;
+    }
+}
@@ -1,10 +1,13 @@
 use bevy::prelude::*;
-use bevy::window::PrimaryWindow;
+use crate::game::table::resource::TableLayout;
 use crate::game::card::component::{Card, CardPosition, Selected, DoubleClick};
 use crate::game::{graveyard::component::Graveyard, turn_player::component::Turn, deck::component::Deck, hand::component::Hand, player::component::Player};
 use crate::game::card::utils::{card_swap, discard_card};
+use crate::game::action_queue::resource::ActionQueue;
+use crate::ui::toast::component::Toast;
 
 // HANDLE CLICK SYSTEMS
+#[allow(clippy::too_many_arguments)]
 pub fn handle_card_click(
     clicked_entity: Entity,
     commands: &mut Commands,
@@ -16,30 +19,35 @@ pub fn handle_card_click(
     graveyard_query: &mut Query<&mut Graveyard>,
     player_query: &Query<(Entity, &Player)>,
     hand_query: &mut Query<&mut Hand>,
-    windows: Query<&Window, With<PrimaryWindow>>,
+    queue: &mut ActionQueue,
+    layout: &TableLayout,
+    from_focus: bool,
 ) {
     // verify: if it is direct discard
     let card_comp = card_query.iter()
         .find(|(entity, _, _)| *entity == clicked_entity)
         .map(|(_, _, card)| card);
 
-    if let Some(card_comp) = card_comp {
-        if matches!(card_comp.position, CardPosition::DrawnCard(player_id) if player_id == turn_query.current_player) {
-            discard_card(clicked_entity, card_query, graveyard_query, turn_query, player_query, commands, selected_query);
-            return;
-        }
+    if let Some(card_comp) = card_comp
+        && matches!(card_comp.position, CardPosition::DrawnCard(player_id) if player_id == turn_query.current_player) {
+        discard_card(clicked_entity, card_query, graveyard_query, turn_query, queue, commands, selected_query);
+        return;
     }
 
     // double click
     let current_time = time.elapsed_secs();
     let mut is_double_click = false;
     
-    if let Some(last_card) = double_click.last_card {
-        if last_card == clicked_entity {
-            let time_diff = current_time - double_click.last_click_time;
-            if time_diff <= double_click.time_limit {
-                is_double_click = true;
-            }
+    // keyboard/gamepad: confirm again on the selected card works as double click
+    if from_focus && selected_query.contains(clicked_entity) {
+        is_double_click = true;
+    }
+
+    if let Some(last_card) = double_click.last_card
+        && last_card == clicked_entity {
+        let time_diff = current_time - double_click.last_click_time;
+        if time_diff <= double_click.time_limit {
+            is_double_click = true;
         }
     }
 
@@ -49,9 +57,8 @@ pub fn handle_card_click(
             commands.entity(selected_entity).remove::<Selected>();
         }
 
-        card_swap(clicked_entity, card_query, graveyard_query, turn_query, hand_query, player_query, windows, commands, selected_query);
+        card_swap(clicked_entity, card_query, graveyard_query, turn_query, hand_query, player_query, queue, layout, commands, selected_query);
         double_click.last_card = None; // reset double click
-        return;
     } else {
         // selection component
         for selected_entity in selected_query.iter() {
@@ -69,10 +76,12 @@ pub fn handle_deck_click(
     mut deck_query: Query<&mut Deck>,
     mut turn_query: ResMut<Turn>,
     card_query: &mut Query<(Entity, &mut Transform, &mut Card), With<Card>>,
+    toasts: &mut MessageWriter<Toast>,
 ) {
     // verify if player already drew a card
     if turn_query.has_drawn_card {
         info!(target: "mygame", "Player already drew a card this turn");
+        toasts.write(Toast::warning("toast.already_drew"));
         return;
     }
 
@@ -96,6 +105,7 @@ pub fn handle_deck_click(
     // verify if it have cards
     if deck.cards_values.is_empty() {
         warn!(target: "mygame", "Deck is empty");
+        toasts.write(Toast::warning("toast.deck_empty"));
         return;
     }
 
@@ -117,10 +127,12 @@ pub fn handle_graveyard_click(
     mut graveyard_query: Query<&mut Graveyard>,
     mut turn_query: ResMut<Turn>,
     card_query: &mut Query<(Entity, &mut Transform, &mut Card), With<Card>>,
+    toasts: &mut MessageWriter<Toast>,
 ) {
     // verify if player already drew a card
     if turn_query.has_drawn_card {
         info!(target: "mygame", "Player already drew a card this turn");
+        toasts.write(Toast::warning("toast.already_drew"));
         return;
     }
 
@@ -144,6 +156,7 @@ pub fn handle_graveyard_click(
     // verify if it have cards
     if graveyard.cards.is_empty() {
         warn!(target: "mygame", "Graveyard is empty");
+        toasts.write(Toast::info("toast.graveyard_empty"));
         return;
     }
     
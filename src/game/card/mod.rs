@@ -2,16 +2,27 @@ use bevy::prelude::*;
 pub mod component;
 pub mod system;
 mod handles;
+mod drag;
 pub mod utils;
 
-use crate::game::{card::{component::DoubleClick, system::{card_face, card_selection, card_visual, setup_cards, configure_texture}}, gamestate::AppState};
+use crate::game::{card::{component::{CardDrag, DoubleClick}, system::{card_face, card_selection, card_visual, setup_cards}}, gamestate::{AppState, PauseState}};
+use crate::game::card::drag::{start_card_drag, update_card_drag, end_card_drag, reset_drag};
+use crate::game::turn_player::system::turn_confirmed;
+use crate::game::action_queue::system::board_idle;
 pub struct CardPlugin;
 
 impl Plugin for CardPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(OnEnter(AppState::Setup), setup_cards)
-        .add_systems(Update, (card_face, configure_texture))
-        .add_systems(Update, (card_selection, card_visual).chain().run_if(in_state(AppState::PlayerTurn)))
+        .add_systems(Update, card_face)
+        .add_systems(Update, (card_selection.run_if(turn_confirmed).run_if(board_idle).run_if(in_state(PauseState::Running)), card_visual).chain().run_if(in_state(AppState::PlayerTurn)))
+        .add_systems(Update, (
+            start_card_drag.before(card_selection).run_if(board_idle),
+            (update_card_drag, end_card_drag).chain().after(card_visual),
+        ).run_if(in_state(AppState::PlayerTurn)).run_if(turn_confirmed).run_if(in_state(PauseState::Running)))
+        .add_systems(OnEnter(PauseState::Paused), reset_drag)
+        .add_systems(OnExit(AppState::PlayerTurn), reset_drag)
+        .init_resource::<CardDrag>()
         .insert_resource(DoubleClick { last_card: None, last_click_time: 0.0, time_limit: 0.4 });
     }
 }
\ No newline at end of file
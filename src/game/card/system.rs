@@ -1,98 +1,114 @@
 use bevy::prelude::*;
-use bevy::window::PrimaryWindow;
-use crate::game::card::component::{Card, CardPosition, CardHandles, CardBack, Suit, Selected, DoubleClick};
+use bevy::picking::{hover::HoverMap, pointer::PointerId};
+use crate::game::card::component::{Card, CardPosition, CardBack, Selected, DoubleClick};
+use crate::game::card::component::{CardLabel, SuitMarker};
+use crate::game::deck::resource::MatchRules;
+use crate::game::deck_theme::{resource::DeckThemes, system::match_art};
+use crate::global::settings::resource::Settings;
 use crate::game::special_cards::resource::SpecialEffect;
 use crate::game::{graveyard::component::Graveyard, turn_player::component::Turn, deck::component::Deck, hand::component::Hand, player::component::Player, special_cards::resource::SpecialCardEffect};
 use crate::game::card::handles::{handle_deck_click, handle_card_click, handle_graveyard_click};
-use crate::ui::card_animation::component::{AnimationType, CardAnimation};
-use bevy::asset::Assets;
-use bevy::image::{Image, ImageSampler};
-use rand::seq::SliceRandom;
-use rand::rng;
-
-// spawn card values after setup and allocate suit/value and textures
-// iterate and mix all cards front/back
-pub fn setup_cards(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let suits = ["coarse", "cup", "gold", "sword"];
-    let mut card_routes = Vec::new();
-
-    for suit_name in suits.iter() {
-        for value in 1..=12 {
-            let route = format!("textures/deck/{}/{}.PNG", suit_name, value);
-            card_routes.push(route);
-        }
-    }
-
-    // load textures
-    let card_handles: Vec<Handle<Image>> = card_routes
-        .into_iter()
-        .map(|route| asset_server.load(route))
-        .collect();
-
-    // card back texture
-    let card_back_handle = asset_server.load("textures/deck/back/back.PNG");
+use crate::game::action_queue::resource::ActionQueue;
+use crate::game::{gamestate::AppState, turn_player::component::HotSeat, table::resource::TableLayout};
+use crate::global::input::resource::GameAction;
+use crate::ui::board_focus::{component::BoardTarget, system::BoardInput};
+use crate::ui::card_animation::component::CardAnimation;
+use crate::ui::tween::component::TweenKind;
+use crate::ui::toast::component::Toast;
 
+// load the art of the selected deck theme for the new round
+pub fn setup_cards(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
+    mut images: ResMut<Assets<Image>>,
+    themes: Res<DeckThemes>,
+    settings: Res<Settings>,
+    rules: Res<MatchRules>,
+) {
     // insert handles resources
-    commands.insert_resource(CardHandles(card_handles.clone()));
-    commands.insert_resource(CardBack(card_back_handle));
-
-    let suits_logic = [Suit::Coarse, Suit::Cup, Suit::Gold, Suit::Sword];
-    let mut cards_data: Vec<(Suit, u8)> = suits_logic
-        .iter()
-        .flat_map(|suit| (1..=12).map(move |value| (suit.clone(), value)))
-        .collect();
+    let (card_handles, card_back) = match_art(&settings, &rules, &themes, &asset_server, &mut atlas_layouts, &mut images);
+    commands.insert_resource(card_handles);
+    commands.insert_resource(card_back);
+}
 
-    cards_data.shuffle(&mut rng());
+fn show_front(sprite: &mut Sprite, card: &Card) {
+    sprite.image = card.front_face.image.clone();
+    sprite.texture_atlas = card.front_face.atlas.clone();
 }
 
+fn show_back(sprite: &mut Sprite, card_back: &CardBack) {
+    sprite.image = card_back.0.clone();
+    sprite.texture_atlas = None;
+}
 
+#[allow(clippy::too_many_arguments)]
 pub fn card_face(
     card_back: Option<Res<CardBack>>,
-    mut query: Query<(&Card, &mut Sprite, Option<&CardAnimation>)>
+    mut query: Query<(&Card, &mut Sprite, Option<&CardAnimation>, Option<&Children>)>,
+    mut label_query: Query<&mut Visibility, With<CardLabel>>,
+    mut marker_query: Query<&mut Visibility, (With<SuitMarker>, Without<CardLabel>)>,
+    settings: Res<Settings>,
+    hot_seat: Res<HotSeat>,
+    turn: Option<Res<Turn>>,
+    state: Res<State<AppState>>,
 ) {
     let Some(card_back) = card_back else { return; };
 
-    for (card, mut sprite, animation) in query.iter_mut() {
-        if let Some(anim) = animation {
-            if anim.animation_type == AnimationType::Flip {
-                if anim.progress < 0.5 {
-                    // Primera mitad: mantener boca abajo
-                    sprite.image = card_back.0.clone();
-                    continue;
-                } else {
-                    // Segunda mitad: mostrar boca arriba
-                    sprite.image = card.front_face.clone();
-                    continue;
-                }
-            }
-        }
-        
-        if card.face_up {
-            // asign front
-            sprite.image = card.front_face.clone();
+    for (card, mut sprite, animation, children) in query.iter_mut() {
+        // hot-seat: keep private cards hidden from the player who is not playing (all visible at round end)
+        let is_private = match &turn {
+            Some(turn) => *state.get() != AppState::RoundEnd && !hot_seat.can_show(card, turn),
+            None => false,
+        };
+
+        let show_face = match animation {
+            _ if is_private => false,
+            // first half of the flip face down, second half face up
+            Some(anim) if anim.kind == TweenKind::Flip => anim.progress() >= 0.5,
+            _ => card.face_up,
+        };
+
+        if show_face {
+            show_front(&mut sprite, card);
         } else {
-            // Asign back
-            sprite.image = card_back.0.clone();
+            show_back(&mut sprite, &card_back);
+        }
+
+        // no art for the card or high contrast: value and suit written over the front
+        let shown = |visible: bool| if show_face && visible { Visibility::Inherited } else { Visibility::Hidden };
+        for child in children.into_iter().flatten() {
+            if let Ok(mut visibility) = label_query.get_mut(*child) {
+                visibility.set_if_neq(shown(card.front_face.label || settings.high_contrast));
+            }
+            if let Ok(mut visibility) = marker_query.get_mut(*child) {
+                visibility.set_if_neq(shown(settings.colorblind_suits));
+            }
         }
     }
 }
 
 // card_selection - detect clicks
-//      detect_card_click
+//      hovered_entities - top card below the mouse
 //          handle_card_click - manage cards
 //              discard_card()
 //              card_swap()
-//      detect_deck_click
 //          handle_deck_click - draw card of the deck
-//      detect_graveyard_click
 //          handle_graveyard_click - draw of the graveyard
 
+// what was clicked with the mouse or confirmed with the keyboard/gamepad focus
+struct ClickedTarget {
+    card: Option<Entity>,
+    deck: bool,
+    graveyard: bool,
+    from_focus: bool,
+}
+
 // card selection system
+#[allow(clippy::too_many_arguments)]
 pub fn card_selection(
     mut commands: Commands,
-    mouse_input: Res<ButtonInput<MouseButton>>,
-    windows: Query<&Window, With<PrimaryWindow>>,
-    camera_query: Query<(&Camera, &GlobalTransform)>,
+    layout: Res<TableLayout>,
     mut card_query: Query<(Entity, &mut Transform, &mut Card), With<Card>>,
     selected_query: Query<Entity, With<Selected>>,
     mut double_click: ResMut<DoubleClick>,
@@ -103,125 +119,151 @@ pub fn card_selection(
     deck_query: Query<&mut Deck>,
     player_query: Query<(Entity, &Player)>,
     special_effect: Option<ResMut<SpecialCardEffect>>,
+    board_input: BoardInput,
+    mut queue: ResMut<ActionQueue>,
+    mut toasts: MessageWriter<Toast>,
 ) {
-    if !mouse_input.just_pressed(MouseButton::Left) {
-        return;
-    }
+    let clicked = if board_input.mouse.just_pressed(MouseButton::Left) {
+        // the drawn card is being dragged (drag and drop handles it)
+        if board_input.drag.entity.is_some() {
+            return;
+        }
+
+        // top card (or the empty graveyard slot) below the mouse
+        let hovered = hovered_entities(&board_input.hover_map).into_iter()
+            .find(|entity| card_query.contains(*entity) || graveyard_query.contains(*entity));
+        let position = hovered.and_then(|entity| card_query.get(entity).ok()).map(|(_, _, card)| card.position.clone());
 
-    // obtain window, camera, cursor/world position
-    let Ok(window) = windows.single() else { return; };
-    let Ok((camera, camera_transform)) = camera_query.single() else { return; };
-    let Some(cursor_pos) = window.cursor_position() else { return; };
-    let Ok(world_pos) = camera.viewport_to_world_2d(camera_transform, cursor_pos) else { return; };
+        ClickedTarget {
+            card: hovered.filter(|entity| card_query.contains(*entity)),
+            deck: position == Some(CardPosition::Deck),
+            graveyard: position == Some(CardPosition::Graveyard) || hovered.is_some_and(|entity| graveyard_query.contains(entity)),
+            from_focus: false,
+        }
+    } else if board_input.input.just_pressed(GameAction::Confirm) && !board_input.focus.overlay_active {
+        // confirm on the focused place of the table
+        let Some(target) = board_input.board_focus.target else { return; };
+        let card = match target {
+            BoardTarget::HandSlot(player_entity, slot) => player_query.get(player_entity).ok()
+                .and_then(|(_, player)| hand_query.get(player.hand).ok())
+                .and_then(|hand| hand.cards.get(slot).copied()),
+            BoardTarget::DrawnCard => card_query.iter()
+                .find(|(_, _, card)| matches!(card.position, CardPosition::DrawnCard(_)))
+                .map(|(entity, _, _)| entity),
+            _ => None,
+        };
+
+        ClickedTarget {
+            card,
+            deck: target == BoardTarget::Deck,
+            graveyard: target == BoardTarget::Graveyard,
+            from_focus: true,
+        }
+    } else if board_input.input.just_pressed(GameAction::Cancel) {
+        ClickedTarget { card: None, deck: false, graveyard: false, from_focus: true }
+    } else {
+        return;
+    };
 
     // verify if it has special effect, run if awaiting_target = true, also awating_own_card = true
     if let Some(mut effect) = special_effect {
         if effect.awaiting_target {
-            if let Some(clicked_entity) = detect_card_click(&card_query, world_pos) {
+            if let Some(clicked_entity) = clicked.card {
                 // verify if clicked card belongs to the rival
-                if let Ok((_card_entity, _card_tr, card)) = card_query.get(clicked_entity) {
-                    if let CardPosition::Hand(hand_owner) = card.position {
-                        if hand_owner != turn_query.current_player {
-                            // save card selected
-                            effect.target_card = Some(clicked_entity);
+                if let Ok((_card_entity, _card_tr, card)) = card_query.get(clicked_entity)
+                    && let CardPosition::Hand(hand_owner) = card.position {
+                    if hand_owner != turn_query.current_player {
+                        // save card selected
+                        effect.target_card = Some(clicked_entity);
 
-                            // verify effect (Swap or Shuffle)
-                            if matches!(effect.effect_type, Some(SpecialEffect::Swap)) {
-                                effect.awaiting_target = false;
-                                effect.awaiting_own_card = true;
-                                info!(target: "mygame", "Now select one of your cards to swap");
-                            } else {
-                                effect.target_player = Some(hand_owner);
-                                effect.awaiting_target = false;
-                            }
-
-                            info!(target: "mygame", "Target player selected for special effect");
+                        // verify effect (Swap or Shuffle)
+                        if matches!(effect.effect_type, Some(SpecialEffect::Swap)) {
+                            effect.awaiting_target = false;
+                            effect.awaiting_own_card = true;
+                            info!(target: "mygame", "Now select one of your cards to swap");
                         } else {
-                            info!(target: "mygame", "Cannot target your own cards");
+                            effect.target_player = Some(hand_owner);
+                            effect.awaiting_target = false;
                         }
+
+                        info!(target: "mygame", "Target player selected for special effect");
+                    } else {
+                        info!(target: "mygame", "Cannot target your own cards");
+                        toasts.write(Toast::warning("toast.own_cards"));
                     }
                 }
                 info!(target: "mygame", "Click on opponent's cards to select target");
                 return;
             }
-        } else if effect.awaiting_own_card {
-            if let Some(clicked_entity) = detect_card_click(&card_query, world_pos) {
-                // verify if clicked card is yours
-                if let Ok((_card_entity, _card_tr, card)) = card_query.get(clicked_entity) {
-                    if let CardPosition::Hand(owner) = card.position {
-                        if owner == turn_query.current_player {
-                            effect.own_card = Some(clicked_entity);
-                            effect.awaiting_own_card = false;
-                        } else {
-                            info!(target: "mygame", "Select one of your cards");
-                        }
-                    }
+        } else if effect.awaiting_own_card
+            && let Some(clicked_entity) = clicked.card {
+            // verify if clicked card is yours
+            if let Ok((_card_entity, _card_tr, card)) = card_query.get(clicked_entity)
+                && let CardPosition::Hand(owner) = card.position {
+                if owner == turn_query.current_player {
+                    effect.own_card = Some(clicked_entity);
+                    effect.awaiting_own_card = false;
+                } else {
+                    info!(target: "mygame", "Select one of your cards to swap");
+                    toasts.write(Toast::warning("toast.select_own"));
                 }
-                info!(target: "mygame", "Click on opponent's cards to select target");
-                return;
             }
+            info!(target: "mygame", "Click on opponent's cards to select target");
+            return;
         }
     }
 
     // detect click in deck
-    if detect_deck_click(world_pos, window) {
-        handle_deck_click(deck_query, turn_query, &mut card_query);
+    if clicked.deck {
+        handle_deck_click(deck_query, turn_query, &mut card_query, &mut toasts);
         return;
     }
 
     // detect click in graveyard 
-    if detect_graveyard_click(world_pos, window) {
-        handle_graveyard_click(graveyard_query, turn_query, &mut card_query);
+    if clicked.graveyard {
+        handle_graveyard_click(graveyard_query, turn_query, &mut card_query, &mut toasts);
         return;
     }
 
     // detect click in hand
-    if let Some(clicked_entity) = detect_card_click(&card_query, world_pos) {
+    if let Some(clicked_entity) = clicked.card {
         handle_card_click(
             clicked_entity, &mut commands, &selected_query, &mut double_click,
-            &time, turn_query, &mut card_query, &mut graveyard_query, &player_query, &mut hand_query, windows
+            &time, turn_query, &mut card_query, &mut graveyard_query, &player_query, &mut hand_query, &mut queue, &layout,
+            clicked.from_focus,
         );
         return;
     }
 
-    // if is not detected any entity
-    if detect_card_click(&card_query, world_pos).is_none() && !detect_deck_click(world_pos, window) && !detect_graveyard_click(world_pos, window) {
-        // deselect all cards
-        for selected_entity in selected_query.iter() {
-            commands.entity(selected_entity).remove::<Selected>();
-        }
+    // if is not detected any entity: deselect all cards
+    for selected_entity in selected_query.iter() {
+        commands.entity(selected_entity).remove::<Selected>();
     }
 }
 
 // feedback visual when selecting
+#[allow(clippy::type_complexity)]
 pub fn card_visual(
-    mut card_query: Query<(&mut Transform, Option<&Selected>, &Card), With<Card>>,
+    mut card_query: Query<(Entity, &mut Transform, Option<&Selected>, &Card), (With<Card>, Without<CardAnimation>)>,
     turn_query: Res<Turn>,
-    windows: Query<&Window, With<PrimaryWindow>>,
+    player_query: Query<&Player>,
+    hand_query: Query<&Hand>,
+    layout: Res<TableLayout>,
 ) {
-    let Ok(window) = windows.single() else { return; };
-
-    for (mut transform, selected, card) in card_query.iter_mut() {
+    for (entity, mut transform, selected, card) in card_query.iter_mut() {
         if let CardPosition::Hand(owner) = card.position {
             if owner == turn_query.current_player {
-                let is_sec_player = transform.translation.y > 0.0;
-                
+                // place of the card at the table
+                let Ok(player) = player_query.get(owner) else { continue; };
+                let Some(slot) = hand_query.get(player.hand).ok()
+                    .and_then(|hand| hand.cards.iter().position(|&card_entity| card_entity == entity)) else { continue; };
+                let slot_pos = layout.hand_position(player.seat, slot);
+
                 if selected.is_some() {
-                    if is_sec_player {
-                        transform.translation.y = window.height() * 0.13; // if is second player
-                    } else {
-                        transform.translation.y = window.height() * -0.13; // if is local player
-                    }
-                    transform.translation.z = 50.0;
+                    transform.translation = (slot_pos + layout.selected_offset(player.seat)).with_z(50.0);
                 } else {
                     // return default position
-                    let base_y = if is_sec_player {
-                        window.height() * 0.15
-                    } else {
-                        window.height() * -0.15
-                    };
-                    transform.translation.y = base_y;
-                    transform.translation.z = 10.0;
+                    transform.translation = slot_pos.with_z(10.0);
                 }
             } else {
                 transform.translation.z = 10.0;
@@ -234,67 +276,14 @@ pub fn card_visual(
     }
 }
 
-pub fn configure_texture(
-    mut images: ResMut<Assets<Image>>,
-    card_handles: Option<Res<CardHandles>>,
-    card_back: Option<Res<CardBack>>,
-) {
-    let Some(card_handles) = card_handles else { return; };
-    let Some(card_back) = card_back else { return; };
-    
-    // front cards
-    for handle in &card_handles.0 {
-        if let Some(image) = images.get_mut(handle) {
-            image.sampler = ImageSampler::nearest();
-        }
-    }
-    
-    // back card
-    if let Some(image) = images.get_mut(&card_back.0) {
-        image.sampler = ImageSampler::nearest();
-    }
-}
-
 // DETECTION CLICK SYSTEMS
-fn detect_card_click(
-    card_query: &Query<(Entity, &mut Transform, &mut Card), With<Card>>,
-    world_pos: Vec2
-) -> Option<Entity> {
-    for (card_entity, card_transform, _card_comp) in card_query.iter() {
-        let card_pos = card_transform.translation;
-        let card_size = Vec2::new(80.0 * 0.7, 120.0 * 0.7);
-        
-        // Check if the click is inside the card
-        if world_pos.x >= card_pos.x - card_size.x / 2.0 
-        && world_pos.x <= card_pos.x + card_size.x / 2.0
-        && world_pos.y >= card_pos.y - card_size.y / 2.0
-        && world_pos.y <= card_pos.y + card_size.y / 2.0 {
-            return Some(card_entity);
-        }
-    }
-    None
-}
+// entities below the mouse (sprite picking: real bounds, rotation and transparency), front first
+pub(crate) fn hovered_entities(hover_map: &HoverMap) -> Vec<Entity> {
+    let Some(hits) = hover_map.get(&PointerId::Mouse) else { return Vec::new(); };
 
-fn detect_deck_click(world_pos: Vec2, window: &Window) -> bool {
-    let deck_x = window.width() * 0.15;
-    let deck_y = window.height() * 0.0;
-    let deck_pos = Vec3::new(deck_x, deck_y, 0.0);
-    let deck_size = Vec2::new(80.0, 120.0);
-    
-    world_pos.x >= deck_pos.x - deck_size.x / 2.0 
-    && world_pos.x <= deck_pos.x + deck_size.x / 2.0
-    && world_pos.y >= deck_pos.y - deck_size.y / 2.0
-    && world_pos.y <= deck_pos.y + deck_size.y / 2.0
+    let mut hovered: Vec<(Entity, f32)> = hits.iter()
+        .map(|(entity, hit)| (*entity, hit.depth))
+        .collect();
+    hovered.sort_by(|a, b| a.1.total_cmp(&b.1));
+    hovered.into_iter().map(|(entity, _)| entity).collect()
 }
-
-fn detect_graveyard_click(world_pos: Vec2, window: &Window) -> bool {
-    let graveyard_x = window.width() * -0.06;
-    let graveyard_y = window.height() * 0.0;
-    let graveyard_pos = Vec3::new(graveyard_x, graveyard_y, 0.0);
-    let graveyard_size = Vec2::new(80.0, 120.0);
-    
-    world_pos.x >= graveyard_pos.x - graveyard_size.x / 2.0 
-    && world_pos.x <= graveyard_pos.x + graveyard_size.x / 2.0
-    && world_pos.y >= graveyard_pos.y - graveyard_size.y / 2.0
-    && world_pos.y <= graveyard_pos.y + graveyard_size.y / 2.0
-}
\ No newline at end of file
@@ -1,7 +1,8 @@
 use bevy::prelude::*;
-use bevy::window::PrimaryWindow;
 use crate::game::card::component::{Card, CardPosition, Selected};
-use crate::game::{graveyard::component::Graveyard, turn_player::component::Turn, hand::component::Hand, player::component::Player};
+use crate::game::table::resource::TableLayout;
+use crate::game::{graveyard::component::{Graveyard, Discarded}, turn_player::component::Turn, hand::component::Hand, player::component::Player};
+use crate::game::action_queue::resource::{ActionQueue, QueuedAction};
 
 // AUXILIAR SYSTEMS
 pub fn discard_card(
@@ -9,35 +10,35 @@ pub fn discard_card(
     card_query: &mut Query<(Entity, &mut Transform, &mut Card), With<Card>>,
     graveyard_query: &mut Query<&mut Graveyard>,
     turn_query: ResMut<Turn>,
-    player_query: &Query<(Entity, &Player)>,
+    queue: &mut ActionQueue,
     commands: &mut Commands,
     selected_query: &Query<Entity, With<Selected>>,
 ) {
-    if let Ok((_, _transform, card)) = card_query.get_mut(clicked_entity) {
-        if matches!(card.position, CardPosition::DrawnCard(player_id) if player_id == turn_query.current_player) {
-            if let Ok((_, _, mut card)) = card_query.get_mut(clicked_entity) {
-                card.position = CardPosition::Graveyard;
-                card.face_up = true;
-                card.is_being_dealt = true;
-
-                // update graveyard
-                if let Ok(mut graveyard) = graveyard_query.single_mut() {
-                    graveyard.cards.push(clicked_entity);
-                    info!(target: "mygame", "Card discarded directly to graveyard: {:?}", clicked_entity);
-                }
+    if let Ok((_, _transform, card)) = card_query.get_mut(clicked_entity)
+        && matches!(card.position, CardPosition::DrawnCard(player_id) if player_id == turn_query.current_player)
+        && let Ok((_, _, mut card)) = card_query.get_mut(clicked_entity) {
+        card.position = CardPosition::Graveyard;
+        card.face_up = true;
+        card.is_being_dealt = true;
 
-                // remove card selection
-                for selected_entity in selected_query.iter() {
-                    commands.entity(selected_entity).remove::<Selected>();
-                }
+        // update graveyard
+        if let Ok(mut graveyard) = graveyard_query.single_mut() {
+            graveyard.cards.push(clicked_entity);
+            commands.entity(clicked_entity).insert(Discarded { player: turn_query.current_player, turn: turn_query.number });
+            info!(target: "mygame", "Card discarded directly to graveyard: {:?}", clicked_entity);
+        }
 
-                change_turn(turn_query, player_query);
-            }
-            return;
+        // remove card selection
+        for selected_entity in selected_query.iter() {
+            commands.entity(selected_entity).remove::<Selected>();
         }
+
+        // the turn changes when the card lands
+        queue.push(QueuedAction::ChangeTurn);
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn card_swap(
     clicked_entity: Entity,
     card_query: &mut Query<(Entity, &mut Transform, &mut Card), With<Card>>,
@@ -45,7 +46,8 @@ pub fn card_swap(
     turn_query: ResMut<Turn>,
     hand_query: &mut Query<&mut Hand>,
     player_query: &Query<(Entity, &Player)>,
-    windows: Query<&Window, With<PrimaryWindow>>,
+    queue: &mut ActionQueue,
+    layout: &TableLayout,
     commands: &mut Commands,
     selected_query: &Query<Entity, With<Selected>>,
 ) {
@@ -71,87 +73,44 @@ pub fn card_swap(
     
         // obtain drawn_card entity and original position card
         let drawn_card_entity = drawn_card.unwrap().0; // .0 indicate the first parameter (Entity)
-        
+
+        // seat of the player and slot of the clicked card
+        let Some((_, player)) = player_query.iter().find(|(entity, _)| *entity == turn_query.current_player) else { return; };
+        let Ok(mut hand) = hand_query.get_mut(player.hand) else { return; };
+        let Some(slot) = hand.cards.iter().position(|&card_entity| card_entity == clicked_entity) else { return; };
+        let Ok(mut graveyard) = graveyard_query.single_mut() else { return; };
+
         // exchange positions
         if let Ok([(_, mut drawn_transform, mut drawn_card), (_, mut clicked_transform, mut clicked_card)]) = 
             card_query.get_many_mut([drawn_card_entity, clicked_entity]) {
-            
-            let clicked_pos = clicked_transform.translation; // obtain position
-            
-            let Ok(window) = windows.single() else { return; };
-            let is_sec_player = clicked_pos.y > 0.0;
-            let base_y = if is_sec_player {
-                window.height() * 0.15
-            } else {
-                window.height() * -0.15
-            };
 
             drawn_card.position = CardPosition::Hand(turn_query.current_player);
             drawn_card.face_up = false;
-            drawn_transform.translation = Vec3::new(clicked_pos.x, base_y, 10.0);
+            drawn_card.is_being_dealt = true; // in transit until the movement ends
+            drawn_transform.translation = layout.hand_position(player.seat, slot);
             clicked_card.position = CardPosition::Graveyard; // card of hand selected to graveyard
             clicked_card.face_up = true; // card front
-            
-            let Ok(window) = windows.single() else { return; };
-            if let Ok(mut graveyard) = graveyard_query.single_mut() {
-                graveyard.cards.push(clicked_entity); // update changes
-                
-                let stack_index = (graveyard.cards.len() - 1) as f32;
-                let max_stack = 8.0;
-                        
-                let (offset_x, offset_y) = if stack_index < max_stack {
-                    (stack_index * 0.5, stack_index * 0.3)
-                } else {
-                    (max_stack * 0.5, max_stack * 0.3)
-                };
-                
-                // small rotation
-                let random_rotation = (rand::random::<f32>() - 0.5) * 0.15;
-                clicked_transform.rotation = Quat::from_rotation_z(random_rotation);
-                
-                clicked_transform.translation = Vec3::new(
-                    window.width() * -0.06 + offset_x,
-                    window.height() * 0.0 + offset_y,
-                    10.0 + stack_index
-                );
-                
-                if let Some((_, player)) = player_query.iter().find(|(entity, _)| *entity == turn_query.current_player) {
-                    if let Ok(mut hand) = hand_query.get_mut(player.hand) {
-                        // remove hand
-                        hand.cards.retain(|&card_entity| card_entity != clicked_entity);
-                        // add new card in hand
-                        hand.cards.push(drawn_card_entity);
-                    }
-                }
-                info!(target: "mygame", "Card swap completed: {:?} -> Hand, {:?} -> Graveyard", drawn_card_entity, clicked_entity);
-                
-                // remove card selection
-                for selected_entity in selected_query.iter() {
-                    commands.entity(selected_entity).remove::<Selected>();
-                }
+            clicked_card.is_being_dealt = true;
 
-                // automatic turn
-                change_turn(turn_query, &player_query);
-            }
-        }
-    }
-}
+            graveyard.cards.push(clicked_entity); // update changes
+            commands.entity(clicked_entity).insert(Discarded { player: turn_query.current_player, turn: turn_query.number });
 
-// system to change turn automatically
-fn change_turn(
-    mut turn: ResMut<Turn>,
-    players: &Query<(Entity, &Player)>,
-) {
-    // obtain player list
-    let players: Vec<Entity> = players.iter()
-        .map(|(entity,_)| entity)
-        .collect();
+            // small rotation
+            let random_rotation = (rand::random::<f32>() - 0.5) * 0.15;
+            clicked_transform.rotation = Quat::from_rotation_z(random_rotation);
+            clicked_transform.translation = layout.graveyard_stack_position(graveyard.cards.len() - 1);
 
-    if let Some(pos) = players.iter().position(|&p| p == turn.current_player) {
-        let next_index = (pos + 1) % players.len();
-        turn.current_player = players[next_index];
-        turn.has_drawn_card = false;
+            // the new card takes the slot of the discarded one
+            hand.cards[slot] = drawn_card_entity;
+            info!(target: "mygame", "Card swap completed: {:?} -> Hand, {:?} -> Graveyard", drawn_card_entity, clicked_entity);
 
-        info!(target: "mygame", "Turn automatically changed to player: {:?}", turn.current_player);
+            // remove card selection
+            for selected_entity in selected_query.iter() {
+                commands.entity(selected_entity).remove::<Selected>();
+            }
+
+            // automatic turn, once both cards are in their place
+            queue.push(QueuedAction::ChangeTurn);
+        }
     }
-}
\ No newline at end of file
+}
@@ -1,7 +1,8 @@
 use bevy::prelude::*;
-use crate::game::{deck::system::spawn_cards, gamestate::AppState};
+use crate::game::{deck::system::{spawn_cards, capture_match_rules}, deck::resource::MatchRules, gamestate::AppState};
 use crate::game::card::system::setup_cards;
 pub mod component;
+pub mod resource;
 mod system;
 
 // deckset
@@ -12,6 +13,8 @@ pub struct DeckPlugin;
 
 impl Plugin for DeckPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(AppState::Setup), spawn_cards.in_set(DeckSet).after(setup_cards));
+        app.init_resource::<MatchRules>()
+        .add_systems(OnExit(AppState::MainMenu), capture_match_rules)
+        .add_systems(OnEnter(AppState::Setup), spawn_cards.in_set(DeckSet).after(setup_cards));
     }
 }
\ No newline at end of file
@@ -0,0 +1,138 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::game::card::component::Suit;
+use crate::game::special_cards::resource::SpecialEffect;
+
+// kind of deck a match is played with
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum DeckType {
+    #[default]
+    Spanish, // 48 cards, 1-12
+    French, // 52 cards, A-K
+}
+
+impl DeckType {
+    pub const ALL: [DeckType; 2] = [DeckType::Spanish, DeckType::French];
+
+    pub fn suits(&self) -> [Suit; 4] {
+        match self {
+            DeckType::Spanish => [Suit::Coarse, Suit::Cup, Suit::Gold, Suit::Sword],
+            DeckType::French => [Suit::Hearts, Suit::Diamonds, Suit::Clubs, Suit::Spades],
+        }
+    }
+
+    pub fn values(&self) -> std::ops::RangeInclusive<u8> {
+        match self {
+            DeckType::Spanish => 1..=12,
+            DeckType::French => 1..=13,
+        }
+    }
+
+    // every card of the deck, jokers have value 0
+    pub fn cards(&self, jokers: bool) -> Vec<(Suit, u8)> {
+        let mut cards: Vec<(Suit, u8)> = self.suits().into_iter()
+            .flat_map(|suit| self.values().map(move |value| (suit, value)))
+            .collect();
+        if jokers {
+            cards.extend([(Suit::Joker, 0), (Suit::Joker, 0)]);
+        }
+        cards
+    }
+
+    // values removed by each selectable composition, the full deck first
+    pub fn compositions(&self) -> &'static [&'static [u8]] {
+        match self {
+            // 40 cards: without 8 and 9
+            DeckType::Spanish => &[&[], &[8, 9]],
+            // 32 cards (piquet): without 2 to 6
+            DeckType::French => &[&[], &[2, 3, 4, 5, 6]],
+        }
+    }
+
+    // points of a (non joker) card at round end
+    pub fn score(&self, value: u8) -> i32 {
+        match self {
+            DeckType::Spanish => value as i32,
+            // figures count 10, the king is the best card
+            DeckType::French => match value {
+                11 | 12 => 10,
+                13 => 0,
+                _ => value as i32,
+            },
+        }
+    }
+
+    // values that can carry each effect, the first one still in the deck is used
+    fn special_candidates(&self, effect: SpecialEffect) -> &'static [u8] {
+        match (self, effect) {
+            (DeckType::Spanish, SpecialEffect::Swap) => &[7, 6],
+            // the sota reveals when the 8 and 9 are removed
+            (DeckType::Spanish, SpecialEffect::Reveal) => &[9, 8, 10],
+            (DeckType::Spanish, SpecialEffect::Shuffle) => &[11, 12],
+            (DeckType::French, SpecialEffect::Reveal) => &[7, 8, 9, 10],
+            (DeckType::French, SpecialEffect::Swap) => &[11, 10],
+            (DeckType::French, SpecialEffect::Shuffle) => &[12, 13],
+        }
+    }
+}
+
+// deck rules of the current match, copied from the settings when it starts
+#[derive(Resource, Clone, Debug)]
+pub struct MatchRules {
+    pub deck_type: DeckType,
+    pub excluded_values: Vec<u8>, // values removed from the deck (8 and 9 for the spanish 40)
+    pub jokers: bool,
+    pub joker_value: i32, // points of a joker, can be negative
+    pub specials: Vec<(u8, SpecialEffect)>, // value of each special card in this deck
+}
+
+impl Default for MatchRules {
+    fn default() -> Self {
+        Self::new(DeckType::Spanish, Vec::new(), false, 0)
+    }
+}
+
+impl MatchRules {
+    pub fn new(deck_type: DeckType, excluded_values: Vec<u8>, jokers: bool, joker_value: i32) -> Self {
+        let mut rules = Self { deck_type, excluded_values, jokers, joker_value, specials: Vec::new() };
+
+        // each effect takes the first candidate still in the deck and not used by another effect
+        for effect in [SpecialEffect::Swap, SpecialEffect::Reveal, SpecialEffect::Shuffle] {
+            let value = deck_type.special_candidates(effect).iter()
+                .copied()
+                .find(|value| rules.has_value(*value) && rules.specials.iter().all(|(used, _)| used != value));
+            match value {
+                Some(value) => rules.specials.push((value, effect)),
+                None => warn!(target: "mygame", "No card left for the {:?} effect", effect),
+            }
+        }
+        rules
+    }
+
+    pub fn has_value(&self, value: u8) -> bool {
+        self.deck_type.values().contains(&value) && !self.excluded_values.contains(&value)
+    }
+
+    // every card of the match deck
+    pub fn cards(&self) -> Vec<(Suit, u8)> {
+        self.deck_type.cards(self.jokers).into_iter()
+            .filter(|(suit, value)| *suit == Suit::Joker || !self.excluded_values.contains(value))
+            .collect()
+    }
+
+    // points of a card at round end
+    pub fn score(&self, suit: Suit, value: u8) -> i32 {
+        match suit {
+            Suit::Joker => self.joker_value,
+            _ => self.deck_type.score(value),
+        }
+    }
+
+    // effect of a special card drawn from the deck
+    pub fn special(&self, suit: Suit, value: u8) -> Option<SpecialEffect> {
+        if suit == Suit::Joker {
+            return None;
+        }
+        self.specials.iter().find(|(special, _)| *special == value).map(|(_, effect)| *effect)
+    }
+}
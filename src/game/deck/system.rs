@@ -1,18 +1,97 @@
+use std::collections::HashMap;
 use bevy::prelude::*;
 use rand::seq::SliceRandom;
-use bevy::window::PrimaryWindow;
 
 use super::component::Deck;
-use crate::game::{card::component::{Card, CardBack, CardHandles, CardPosition, Suit}, gamestate::GameEntity};
+use crate::game::{card::component::{Card, CardBack, CardHandles, CardLabel, CardPosition, Suit, SuitMarker, CARD_SIZE}, gamestate::GameEntity, table::resource::TableLayout};
+use crate::game::deck::resource::MatchRules;
+use crate::global::locale::resource::Locale;
+use crate::global::settings::resource::Settings;
 
-pub fn spawn_cards(mut commands: Commands, card_handles: Option<Res<CardHandles>>, card_back: Option<Res<CardBack>>, windows: Query<&Window, With<PrimaryWindow>>,) {
-    let suits = [Suit::Coarse, Suit::Cup, Suit::Gold, Suit::Sword];
+// the deck of a match is fixed when it starts, changing the setting affects the next match
+pub fn capture_match_rules(mut commands: Commands, settings: Res<Settings>) {
+    let rules = MatchRules::new(settings.deck_type, settings.excluded_values.clone(), settings.jokers, settings.joker_value);
+    info!(target: "mygame", "Match deck: {:?}, {} cards, specials {:?}", rules.deck_type, rules.cards().len(), rules.specials);
+    commands.insert_resource(rules);
+}
 
+// value and suit over the card on a light plate, shown by card_face (text faces, high contrast)
+fn card_label(suit: Suit, value: u8, locale: &Locale) -> impl Bundle {
+    let color = if suit.is_red() { Color::srgb(0.75, 0.1, 0.1) } else { Color::srgb(0.1, 0.1, 0.1) };
+    (
+        // same color as the blank fronts, so it only shows over the art
+        Sprite::from_color(Color::srgba(1.0, 1.0, 1.0, 0.95), Vec2::new(CARD_SIZE.x * 0.8, 56.0)),
+        Transform::from_xyz(0.0, 8.0, 0.1),
+        Visibility::Hidden,
+        Pickable::IGNORE, // clicks go to the card
+        CardLabel,
+        children![(
+            Text2d::new(locale.card_short(value, suit)),
+            TextFont { font_size: 30.0, ..default() },
+            TextColor(color),
+            Transform::from_xyz(0.0, 0.0, 0.1),
+            children![(
+                TextSpan::new(format!("\n{}", locale.suit_name(suit))),
+                TextFont { font_size: 13.0, ..default() },
+                TextColor(color),
+            )],
+        )],
+    )
+}
+
+// each suit a different shape and a color that stays apart for the usual color blindness (Okabe-Ito palette)
+fn suit_marker_shape(suit: Suit, meshes: &mut Assets<Mesh>) -> (Handle<Mesh>, Color) {
+    match suit.index() {
+        0 => (meshes.add(Circle::new(6.0)), Color::srgb(0.84, 0.37, 0.0)),
+        1 => (meshes.add(Rhombus::new(11.0, 14.0)), Color::srgb(0.0, 0.45, 0.7)),
+        2 => (meshes.add(Triangle2d::new(Vec2::new(0.0, 7.0), Vec2::new(-7.0, -6.0), Vec2::new(7.0, -6.0))), Color::srgb(0.0, 0.62, 0.45)),
+        3 => (meshes.add(Rectangle::new(11.0, 11.0)), Color::srgb(0.1, 0.1, 0.1)),
+        _ => (meshes.add(RegularPolygon::new(7.0, 5)), Color::srgb(0.8, 0.47, 0.65)),
+    }
+}
+
+// marker on a white disc in the top left corner, shown by card_face
+fn suit_marker(
+    suit: Suit,
+    card_size: Vec2,
+    markers: &mut HashMap<Suit, (Handle<Mesh>, Handle<ColorMaterial>)>,
+    disc: &(Handle<Mesh>, Handle<ColorMaterial>),
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<ColorMaterial>,
+) -> impl Bundle {
+    let (shape, color) = markers.entry(suit).or_insert_with(|| {
+        let (mesh, color) = suit_marker_shape(suit, meshes);
+        (mesh, materials.add(color))
+    }).clone();
+
+    (
+        Mesh2d(disc.0.clone()),
+        MeshMaterial2d(disc.1.clone()),
+        Transform::from_xyz(-card_size.x / 2.0 + 12.0, card_size.y / 2.0 - 12.0, 0.3),
+        Visibility::Hidden,
+        Pickable::IGNORE,
+        SuitMarker,
+        children![(
+            Mesh2d(shape),
+            MeshMaterial2d(color),
+            Transform::from_xyz(0.0, 0.0, 0.01),
+        )],
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn spawn_cards(
+    mut commands: Commands,
+    card_handles: Option<Res<CardHandles>>,
+    card_back: Option<Res<CardBack>>,
+    layout: Res<TableLayout>,
+    rules: Res<MatchRules>,
+    locale: Res<Locale>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
     // generate all combinations (suit-value)
-    let mut cards: Vec<(Suit, u8)> = suits
-        .iter()
-        .flat_map(|suit| (1..=12).map(move |value| (suit.clone(), value)))
-        .collect();
+    let mut cards: Vec<(Suit, u8)> = rules.cards();
 
     // randomize cards
     let mut rng = rand::rng();
@@ -25,54 +104,41 @@ pub fn spawn_cards(mut commands: Commands, card_handles: Option<Res<CardHandles>
     let Some(card_back) = card_back else { return; };
     let Some(card_handles) = card_handles else { return; };
 
+    // suit markers share their meshes and materials
+    let card_size = card_handles.card_size.unwrap_or(CARD_SIZE);
+    let disc = (meshes.add(Circle::new(10.0)), materials.add(Color::WHITE));
+    let mut markers = HashMap::new();
+
+    let total = cards.len();
     for (suit, value) in cards {
+        let front = card_handles.front(suit, value);
 
-        let suit_idx = match suit {
-            Suit::Coarse => 0,
-            Suit::Cup    => 1,
-            Suit::Gold   => 2,
-            Suit::Sword  => 3,
-        };
-        let idx = suit_idx * 12 + (value as usize - 1);
-        let front = card_handles.0[idx].clone();
-        let handle = front;
-
-        // obtain window
-        let Ok(window) = windows.single() else { 
-            warn!("No primary window found");
-            return; 
-        };
-
-        // offsets
-        let stack_index = card_entities.len() as f32;  // use card spawned index
-        let max_stack_effect = 15.0;  // first 15 cards add offset
-
-        // Apply offset only if it is one of the first cards
-        let (stack_offset_x, stack_offset_y) = if stack_index < max_stack_effect {
-            (stack_index * 0.4, stack_index * 0.3)
-        } else {
-            // others cards without offset
-            (max_stack_effect * 0.4, max_stack_effect * 0.3)
-        };
+        // first card of the deck on top of the pile
+        let depth = total - 1 - card_entities.len();
 
         let card_entity = commands.spawn((
-            Sprite::from_image(card_back.0.clone()),
-            Transform::from_xyz(
-                window.width() * 0.15 + stack_offset_x, 
-                window.height() * 0.0 + stack_offset_y, 
-                idx as f32
-            ).with_scale(Vec3::splat(0.7)),
+            Sprite {
+                image: card_back.0.clone(),
+                custom_size: card_handles.card_size,
+                ..default()
+            },
+            Transform::from_translation(layout.deck_stack_position(depth)).with_scale(layout.card_scale()),
+            Pickable::default(),
             Card {
                 suit,
                 value,
                 face_up: false,
                 owner_id: None,
                 position: CardPosition::Deck,
-                front_face: handle.clone(),
+                front_face: front,
                 from_deck: false,
                 is_being_dealt: false,
             },
             GameEntity,
+            children![
+                card_label(suit, value, &locale),
+                suit_marker(suit, card_size, &mut markers, &disc, &mut meshes, &mut materials),
+            ],
         )).id();
 
         card_entities.push(card_entity);
@@ -0,0 +1,16 @@
+use bevy::prelude::*;
+
+pub mod resource;
+pub mod system;
+
+use crate::game::deck_theme::system::{discover_themes, configure_texture, apply_deck_theme};
+
+pub struct DeckThemePlugin;
+
+impl Plugin for DeckThemePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(PreStartup, discover_themes)
+            .add_systems(Update, (apply_deck_theme, configure_texture).chain());
+    }
+}
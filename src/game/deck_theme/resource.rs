@@ -0,0 +1,89 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+use crate::game::deck::resource::DeckType;
+
+// art shipped with the game, always available
+pub const BUILTIN_THEME: &str = "textures/deck";
+// one builtin theme per deck, listed before the drop-in ones
+pub const BUILTIN_THEMES: [&str; 2] = [BUILTIN_THEME, "textures/deck_french"];
+// drop-in themes: assets/themes/<folder>/theme.ron
+pub const THEMES_DIR: &str = "themes";
+pub const MANIFEST_FILE: &str = "theme.ron";
+
+// how the card images are sampled when scaled
+#[derive(Deserialize, Clone, Copy, PartialEq, Debug, Default)]
+pub enum TextureFilter {
+    #[default]
+    Nearest, // pixel art
+    Linear, // smooth, for high resolution art
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub enum ThemeFronts {
+    // one image per card, {suit} and {value} are replaced: "cup/7.PNG"
+    Files(String),
+    // single image, one row per suit (coarse/hearts, cup/diamonds, gold/clubs, sword/spades)
+    // and one column per value
+    Atlas {
+        image: String,
+        tile_size: (u32, u32),
+        columns: u32,
+        rows: u32,
+    },
+}
+
+// theme.ron, paths are relative to the theme folder
+#[derive(Deserialize, Clone, Debug)]
+pub struct ThemeManifest {
+    pub name: String,
+    pub fronts: ThemeFronts,
+    pub back: String,
+    #[serde(default)]
+    pub joker: Option<String>, // jokers without it get a blank front with their label
+    #[serde(default)]
+    pub deck: DeckType, // deck the art is drawn for
+    #[serde(default)]
+    pub filter: TextureFilter,
+    #[serde(default)]
+    pub card_size: Option<(f32, f32)>, // draw size, image size if missing
+}
+
+pub struct DeckTheme {
+    pub id: String, // folder inside assets, saved in the settings
+    pub manifest: ThemeManifest,
+}
+
+impl DeckTheme {
+    // asset path of a file of the theme
+    pub fn path(&self, file: &str) -> String {
+        format!("{}/{}", self.id, file)
+    }
+}
+
+// themes found at startup, the builtin ones first
+#[derive(Resource, Default)]
+pub struct DeckThemes {
+    pub themes: Vec<DeckTheme>,
+}
+
+impl DeckThemes {
+    // selected theme, the first one for the deck if it was removed or drawn for another deck,
+    // None when no theme has art for the deck (text faces)
+    pub fn selected(&self, id: &str, deck: DeckType) -> Option<&DeckTheme> {
+        let mut for_deck = self.themes.iter().filter(|theme| theme.manifest.deck == deck);
+        self.themes.iter()
+            .find(|theme| theme.id == id && theme.manifest.deck == deck)
+            .or_else(|| for_deck.next())
+    }
+
+    // id of the next/previous theme of the deck (direction = -1 or 1)
+    pub fn cycle(&self, id: &str, deck: DeckType, direction: i32) -> Option<String> {
+        let themes: Vec<&DeckTheme> = self.themes.iter().filter(|theme| theme.manifest.deck == deck).collect();
+        if themes.is_empty() {
+            return None;
+        }
+        let index = themes.iter().position(|theme| theme.id == id).unwrap_or(0) as i32;
+        let next = (index + direction).rem_euclid(themes.len() as i32) as usize;
+        Some(themes[next].id.clone())
+    }
+}
@@ -0,0 +1,224 @@
+use bevy::prelude::*;
+use bevy::image::ImageSampler;
+use bevy::asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use std::collections::HashMap;
+use std::fs;
+use crate::game::card::component::{Card, CardBack, CardFront, CardHandles, Suit, CARD_SIZE};
+use crate::game::deck::resource::{DeckType, MatchRules};
+use crate::game::deck_theme::resource::{DeckTheme, DeckThemes, ThemeFronts, ThemeManifest, TextureFilter, BUILTIN_THEMES, THEMES_DIR, MANIFEST_FILE};
+use crate::global::assets_dir;
+use crate::global::settings::resource::Settings;
+
+fn read_manifest(id: &str) -> Option<ThemeManifest> {
+    let path = assets_dir().join(id).join(MANIFEST_FILE);
+    let content = fs::read_to_string(&path).ok()?;
+
+    match ron::from_str(&content) {
+        Ok(manifest) => Some(manifest),
+        Err(err) => {
+            warn!(target: "mygame", "Could not parse {:?}: {}", path, err);
+            None
+        }
+    }
+}
+
+// builtin themes plus every folder in assets/themes with a manifest
+pub fn discover_themes(mut commands: Commands) {
+    let mut themes = DeckThemes::default();
+
+    for id in BUILTIN_THEMES {
+        if let Some(manifest) = read_manifest(id) {
+            themes.themes.push(DeckTheme { id: id.to_string(), manifest });
+        } else {
+            warn!(target: "mygame", "Builtin deck theme manifest is missing: {}", id);
+        }
+    }
+
+    let mut folders: Vec<String> = fs::read_dir(assets_dir().join(THEMES_DIR))
+        .map(|entries| entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_dir())
+            .map(|entry| format!("{}/{}", THEMES_DIR, entry.file_name().to_string_lossy()))
+            .collect())
+        .unwrap_or_default();
+    folders.sort();
+
+    for id in folders {
+        let Some(manifest) = read_manifest(&id) else { continue; };
+        info!(target: "mygame", "Deck theme found: {} ({})", manifest.name, id);
+        themes.themes.push(DeckTheme { id, manifest });
+    }
+
+    commands.insert_resource(themes);
+}
+
+// plain front of the given size, the CardLabel of the card shows its value
+fn blank_front(size: Vec2, images: &mut Assets<Image>) -> CardFront {
+    let image = images.add(Image::new_fill(
+        Extent3d { width: size.x as u32, height: size.y as u32, depth_or_array_layers: 1 },
+        TextureDimension::D2,
+        &[245, 240, 230, 255],
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::RENDER_WORLD,
+    ));
+    CardFront { image, atlas: None, label: true }
+}
+
+// load the images of a theme (suit by suit), jokers only when the match uses them
+pub fn load_theme_art(
+    theme: &DeckTheme,
+    jokers: bool,
+    asset_server: &AssetServer,
+    atlas_layouts: &mut Assets<TextureAtlasLayout>,
+    images: &mut Assets<Image>,
+) -> (CardHandles, CardBack) {
+    let cards = theme.manifest.deck.cards(false);
+    let card_size = theme.manifest.card_size.map(|(width, height)| Vec2::new(width, height));
+
+    let mut fronts: HashMap<_, CardFront> = match &theme.manifest.fronts {
+        ThemeFronts::Files(pattern) => cards.into_iter()
+            .map(|(suit, value)| {
+                let file = pattern.replace("{suit}", suit.key()).replace("{value}", &value.to_string());
+                ((suit, value), CardFront { image: asset_server.load(theme.path(&file)), atlas: None, label: false })
+            })
+            .collect(),
+        ThemeFronts::Atlas { image, tile_size, columns, rows } => {
+            let image: Handle<Image> = asset_server.load(theme.path(image));
+            let layout = atlas_layouts.add(TextureAtlasLayout::from_grid(UVec2::new(tile_size.0, tile_size.1), *columns, *rows, None, None));
+            cards.into_iter()
+                .map(|(suit, value)| ((suit, value), CardFront {
+                    image: image.clone(),
+                    atlas: Some(TextureAtlas {
+                        layout: layout.clone(),
+                        index: suit.index() * *columns as usize + (value as usize - 1),
+                    }),
+                    label: false,
+                }))
+                .collect()
+        }
+    };
+
+    if jokers {
+        let joker = match &theme.manifest.joker {
+            Some(file) => CardFront { image: asset_server.load(theme.path(file)), atlas: None, label: false },
+            None => blank_front(card_size.unwrap_or(CARD_SIZE), images),
+        };
+        fronts.insert((Suit::Joker, 0), joker);
+    }
+
+    let handles = CardHandles {
+        theme: theme.id.clone(),
+        fronts,
+        filter: theme.manifest.filter,
+        card_size,
+    };
+    (handles, CardBack(asset_server.load(theme.path(&theme.manifest.back))))
+}
+
+// no theme has art for the deck: blank fronts, the CardLabel of each card shows its value
+fn text_face_art(
+    deck: DeckType,
+    jokers: bool,
+    themes: &DeckThemes,
+    asset_server: &AssetServer,
+    images: &mut Assets<Image>,
+) -> (CardHandles, CardBack) {
+    let blank = blank_front(CARD_SIZE, images);
+    let fronts = deck.cards(jokers).into_iter()
+        .map(|card| (card, blank.clone()))
+        .collect();
+
+    // any back works, the builtin one first
+    let back = match themes.themes.first() {
+        Some(theme) => asset_server.load(theme.path(&theme.manifest.back)),
+        None => Handle::default(),
+    };
+
+    let handles = CardHandles {
+        theme: String::new(),
+        fronts,
+        filter: TextureFilter::Linear,
+        card_size: Some(CARD_SIZE),
+    };
+    (handles, CardBack(back))
+}
+
+// art for the deck of the match: the selected theme or text faces
+pub fn match_art(
+    settings: &Settings,
+    rules: &MatchRules,
+    themes: &DeckThemes,
+    asset_server: &AssetServer,
+    atlas_layouts: &mut Assets<TextureAtlasLayout>,
+    images: &mut Assets<Image>,
+) -> (CardHandles, CardBack) {
+    match themes.selected(&settings.deck_theme, rules.deck_type) {
+        Some(theme) => load_theme_art(theme, rules.jokers, asset_server, atlas_layouts, images),
+        None => {
+            info!(target: "mygame", "No deck theme for {:?}, using text faces", rules.deck_type);
+            text_face_art(rules.deck_type, rules.jokers, themes, asset_server, images)
+        }
+    }
+}
+
+// set the filtering of the theme on the loaded images
+pub fn configure_texture(
+    mut images: ResMut<Assets<Image>>,
+    card_handles: Option<Res<CardHandles>>,
+    card_back: Option<Res<CardBack>>,
+) {
+    let Some(card_handles) = card_handles else { return; };
+    let Some(card_back) = card_back else { return; };
+
+    let sampler = match card_handles.filter {
+        TextureFilter::Nearest => ImageSampler::nearest(),
+        TextureFilter::Linear => ImageSampler::linear(),
+    };
+
+    // front cards
+    for front in card_handles.fronts.values() {
+        if let Some(image) = images.get_mut(&front.image) {
+            image.sampler = sampler.clone();
+        }
+    }
+
+    // back card
+    if let Some(image) = images.get_mut(&card_back.0) {
+        image.sampler = sampler;
+    }
+}
+
+// theme changed in the settings during a game: swap the art of the cards on the table
+#[allow(clippy::too_many_arguments)]
+pub fn apply_deck_theme(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    rules: Res<MatchRules>,
+    themes: Res<DeckThemes>,
+    card_handles: Option<Res<CardHandles>>,
+    asset_server: Res<AssetServer>,
+    mut atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
+    mut images: ResMut<Assets<Image>>,
+    mut card_query: Query<(&mut Card, &mut Sprite)>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    let Some(card_handles) = card_handles else { return; };
+    // the deck of the match does not change, only its art
+    let Some(theme) = themes.selected(&settings.deck_theme, rules.deck_type) else { return; };
+    if card_handles.theme == theme.id {
+        return;
+    }
+
+    let (handles, back) = match_art(&settings, &rules, &themes, &asset_server, &mut atlas_layouts, &mut images);
+    for (mut card, mut sprite) in card_query.iter_mut() {
+        card.front_face = handles.front(card.suit, card.value);
+        sprite.custom_size = handles.card_size;
+    }
+
+    commands.insert_resource(handles);
+    commands.insert_resource(back);
+    info!(target: "mygame", "Deck theme: {}", theme.manifest.name);
+}
@@ -9,6 +9,30 @@ pub enum AppState {
     RoundEnd // end button
 }
 
+// screens inside the main menu
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default, SubStates)]
+#[source(AppState = AppState::MainMenu)]
+pub enum MenuPage {
+    #[default]
+    Main, // play/exit buttons
+    Stats, // profiles and statistics
+}
+
+// settings screen, can be opened over any app state
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default, States)]
+pub enum SettingsState {
+    #[default]
+    Closed,
+    Open,
+}
+
+// pause menu during a game, freezes time and game input
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default, States)]
+pub enum PauseState {
+    #[default]
+    Running,
+    Paused,
+}
 
 // component for despawn all entities to close setup state
 #[derive(Component)]
@@ -18,7 +42,10 @@ pub struct GameStatePlugin;
 
 impl Plugin for GameStatePlugin {
     fn build(&self, app: &mut App) {
-        app.init_state::<AppState>();
+        app.init_state::<AppState>()
+            .add_sub_state::<MenuPage>()
+            .init_state::<SettingsState>()
+            .init_state::<PauseState>();
     }
 }
 
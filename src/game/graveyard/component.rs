@@ -3,4 +3,11 @@ use bevy::prelude::{Component, Entity};
 #[derive(Component)]
 pub struct Graveyard {
     pub cards: Vec<Entity>
+}
+
+// who threw a card of the graveyard and when, for the discard history
+#[derive(Component, Clone, Copy)]
+pub struct Discarded {
+    pub player: Entity,
+    pub turn: u32,
 }
\ No newline at end of file
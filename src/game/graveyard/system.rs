@@ -1,18 +1,17 @@
 use bevy::prelude::*;
-use bevy::window::PrimaryWindow;
 
+use crate::game::card::component::CARD_SIZE;
 use crate::game::gamestate::GameEntity;
+use crate::game::table::resource::TableLayout;
 use crate::game::graveyard::component::Graveyard;
 
-pub fn spawn_graveyard(mut commands: Commands, windows: Query<&Window, With<PrimaryWindow>>,) {
-    let Ok(window) = windows.single() else { 
-        warn!("No primary window found");
-        return; 
-    };
-
+pub fn spawn_graveyard(mut commands: Commands, layout: Res<TableLayout>) {
     commands.spawn((
         Graveyard { cards: Vec::new() },
-        Transform::from_xyz(window.width() * -0.06, window.height() * 0.0, 5.0),
+        // faint slot, so the empty pile can be clicked and used as drop zone
+        Sprite::from_color(Color::srgba(1.0, 1.0, 1.0, 0.08), CARD_SIZE),
+        Transform::from_translation(layout.graveyard_position()).with_scale(layout.card_scale()),
+        Pickable::default(),
         GameEntity,
     ));
     info!(target: "mygame", "Graveyard spawned");
@@ -1,26 +1,38 @@
 use bevy::prelude::*;
-use bevy::window::PrimaryWindow;
 
 use super::component::Hand;
 
 use crate::game::deck::component::Deck;
 use crate::game::player::component::Player;
 use crate::game::card::component::{Card, CardPosition};
-use crate::ui::card_animation::component::{CardAnimation, AnimationType, AnimationState};
+use crate::game::turn_player::component::HotSeat;
+use crate::game::table::resource::TableLayout;
+use crate::ui::toast::component::Toast;
+use crate::ui::card_animation::component::deal_animation;
+use crate::ui::deck_shuffle::component::shuffle_intro_time;
+use crate::global::settings::resource::Settings;
 
+#[allow(clippy::too_many_arguments)]
 pub fn deal_initial_hands (
     mut commands: Commands,
     mut deck_query: Query<&mut Deck, With<Deck>>,
     player_query: Query<(Entity, &Player), With<Player>>,
     mut card_query: Query<(&mut Card, &mut Transform)>,
     mut hand_query: Query<&mut Hand>,
-    windows: Query<&Window, With<PrimaryWindow>>,
+    layout: Res<TableLayout>,
+    hot_seat: Res<HotSeat>,
+    settings: Res<Settings>,
+    mut toasts: MessageWriter<Toast>,
 ) {
+    // the deal starts after the round start shuffle
+    let intro = shuffle_intro_time(&settings);
+
     // search deck
     let mut deck = match deck_query.single_mut() {
         Ok(d) => d,
         Err(_) => {
             error!(target: "mygame","❌ deck not founded.");
+            toasts.write(Toast::error("toast.deal_no_deck"));
             return;
         }
     };
@@ -29,56 +41,39 @@ pub fn deal_initial_hands (
     for (i, (player_entity, player_component)) in player_query.iter().enumerate() {
         if deck.cards_values.len() < 4 {
             warn!(target: "mygame","⚠️ There are not enough cards for this player");
+            toasts.write(Toast::warning("toast.deal_not_enough"));
             continue;
         }
         // take 4 deck cards
         let hand_cards: Vec<Entity> = deck.cards_values.drain(0..4).collect();
 
-        // obtain window dimensions
-        let Ok(window) = windows.single() else { return; };
-
         // player positions
-        let positions = match i {
-            0 => get_player_positions(0, window.width(), window.height()),
-            1 => get_player_positions(1, window.width(), window.height()),
-            _ => get_player_positions(0, window.width(), window.height()),// default
-        };
+        let positions = layout.hand_positions(player_component.seat);
 
         // obtain deck position
-        let deck_position = Vec3::new(window.width() * 0.15, 0.0, 20.0);
+        let deck_position = layout.deck_position();
 
         // iterate deck cards and distribute it to players
         for (j, &card_e) in hand_cards.iter().enumerate() {
             if let Ok((mut card, mut tf)) = card_query.get_mut(card_e) {
                 card.owner_id = Some(player_entity);
                 card.position = CardPosition::Hand(player_entity);
-                card.face_up  = i == 0 && j < 2;
+                card.face_up  = (i == 0 || hot_seat.enabled) && j < 2; // in hot-seat every player peeks at two cards
                 card.is_being_dealt = true;
 
                 let target_pos = positions[j]; // save target position
                 
                 tf.translation = deck_position; // start cards in deck
-                tf.scale = Vec3::splat(0.7);
+                tf.scale = layout.card_scale();
 
                 // calculate delay
-                let delay = (i * 4 + j) as f32 * 0.15;
+                let delay = intro + (i * 4 + j) as f32 * 0.15;
 
                 info!(target: "mygame", "Setting up card {} for player {}: delay={}, target={:?}", 
                 j, i, delay, target_pos);
                 
                 // insert deal animation
-                commands.entity(card_e).insert(CardAnimation {
-                    animation_type: AnimationType::Deal,
-                    progress: 0.0,
-                    duration: 0.3,
-                    state: AnimationState::WaitingToStart,  // o Animating
-                    original_position: deck_position,
-                    original_scale: tf.scale,
-                    original_rotation: tf.rotation,
-                    target_position: Some(target_pos),
-                    delay: delay,
-                    delay_elapsed: 0.0,
-                });
+                commands.entity(card_e).insert(deal_animation(deck_position, target_pos, delay));
             }
         }
 
@@ -87,32 +82,3 @@ pub fn deal_initial_hands (
         }
     }
 }
-
-// player positions auxiliar system
-pub fn get_player_positions(player_i: usize, window_width: f32, window_height: f32) -> [Vec3; 4] {
-    match player_i {
-        0 => {
-            let base_y = window_height * -0.15;  // 15% down
-            let base_x = window_width * -0.06;  // 8% left
-            let gap = window_width * 0.06;       // 7% win width
-            [
-                Vec3::new(base_x, base_y, 10.0),
-                Vec3::new(base_x + gap, base_y, 11.0),
-                Vec3::new(base_x + gap * 2.0, base_y, 12.0),
-                Vec3::new(base_x + gap * 3.0, base_y, 13.0),
-            ]
-        },
-        1 => {
-            let base_y = window_height * 0.15;  // 15% up
-            let base_x = window_width * -0.06;
-            let gap = window_width * 0.06;
-            [
-                Vec3::new(base_x, base_y, 10.0),
-                Vec3::new(base_x + gap, base_y, 11.0),
-                Vec3::new(base_x + gap * 2.0, base_y, 12.0),
-                Vec3::new(base_x + gap * 3.0, base_y, 13.0),
-            ]
-        },
-        _ => get_player_positions(0, window_width, window_height)
-    }
-}
\ No newline at end of file
@@ -11,6 +11,10 @@ pub mod card;
 pub mod turn_player;
 pub mod round_end;
 pub mod special_cards;
+pub mod profile;
+pub mod table;
+pub mod deck_theme;
+pub mod action_queue;
 
 use gamestate::GameStatePlugin;
 use player::PlayerPlugin;
@@ -21,6 +25,10 @@ use card::CardPlugin;
 use turn_player::TurnPlugin;
 use round_end::RoundEndPlugin;
 use special_cards::SpecialCardsPlugin;
+use profile::ProfilePlugin;
+use table::TablePlugin;
+use deck_theme::DeckThemePlugin;
+use action_queue::ActionQueuePlugin;
 
 pub struct GamePlugin;
 
@@ -36,6 +44,10 @@ impl Plugin for GamePlugin {
         .add_plugins(TurnPlugin)
         .add_plugins(RoundEndPlugin)
         .add_plugins(SpecialCardsPlugin)
+        .add_plugins(ProfilePlugin)
+        .add_plugins(TablePlugin)
+        .add_plugins(DeckThemePlugin)
+        .add_plugins(ActionQueuePlugin)
 
         // clean all entities on exit
         .add_systems(OnEnter(AppState::MainMenu), cleanup_game_entities);
@@ -4,5 +4,7 @@ use bevy::prelude::{Component, Entity};
 pub struct Player {
     pub name: String,
     pub hand: Entity,
-    pub is_local_player: bool
+    pub is_local_player: bool,
+    pub profile: usize, // index in Profiles
+    pub seat: usize, // place at the table (TableLayout)
 }
\ No newline at end of file
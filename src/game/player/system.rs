@@ -2,11 +2,14 @@ use bevy::prelude::*;
 use crate::game::player::component::Player;
 use crate::game::hand::component::Hand;
 use crate::game::gamestate::GameEntity;
+use crate::game::profile::resource::Profiles;
 
-pub fn spawn_player(mut commands: Commands) {
-    let player_names = ["Player 1", "Player 2"];
+pub fn spawn_player(mut commands: Commands, profiles: Res<Profiles>) {
+    for i in 0..2 {
+        // take name of the profile sitting at this seat
+        let profile = profiles.seat_profile(i);
+        let name = &profiles.profiles[profile].name;
 
-    for (i, name) in player_names.iter().enumerate() {
         // create hand
         let hand = commands.spawn((
             Hand { cards: Vec::new() },
@@ -16,8 +19,10 @@ pub fn spawn_player(mut commands: Commands) {
         commands.spawn((
             Player {
                 name: name.to_string(),
-                hand: hand,
-                is_local_player: i == 0 // first player = local
+                hand,
+                is_local_player: i == 0, // first player = local
+                profile,
+                seat: i,
             },
             GameEntity,
         ));
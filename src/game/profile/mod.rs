@@ -0,0 +1,18 @@
+use bevy::prelude::*;
+use crate::game::gamestate::AppState;
+use crate::game::round_end::system::calculate_scores;
+use crate::global::locale::system::load_locale;
+
+pub mod resource;
+pub mod system;
+use system::{load_profiles, record_round_stats};
+
+pub struct ProfilePlugin;
+
+impl Plugin for ProfilePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(PreStartup, load_profiles.after(load_locale))
+            .add_systems(OnEnter(AppState::RoundEnd), record_round_stats.after(calculate_scores));
+    }
+}
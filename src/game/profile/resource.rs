@@ -0,0 +1,71 @@
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+// file inside the save folder
+pub const PROFILES_FILE: &str = "profiles.ron";
+
+// accumulated statistics of one profile
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
+pub struct ProfileStats {
+    pub rounds_played: u32,
+    pub wins: u32,
+    pub total_score: i32, // sum of final hand scores, used for the average
+    pub special_effects_used: u32,
+    pub round_calls_won: u32,
+    pub round_calls_lost: u32,
+}
+
+impl ProfileStats {
+    pub fn average_score(&self) -> f32 {
+        if self.rounds_played == 0 {
+            return 0.0;
+        }
+        self.total_score as f32 / self.rounds_played as f32
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Profile {
+    pub name: String,
+    pub stats: ProfileStats,
+}
+
+impl Profile {
+    pub fn new(name: &str) -> Self {
+        Self { name: name.to_string(), stats: ProfileStats::default() }
+    }
+}
+
+// local profiles saved on disk, seats = profile index used by each player
+#[derive(Resource, Serialize, Deserialize, Clone, Debug)]
+pub struct Profiles {
+    pub profiles: Vec<Profile>,
+    pub seats: [usize; 2],
+}
+
+// no profiles yet, load_profiles creates one per seat with a localized name
+impl Default for Profiles {
+    fn default() -> Self {
+        Self {
+            profiles: Vec::new(),
+            seats: [0, 1],
+        }
+    }
+}
+
+impl Profiles {
+    // profile sitting at a seat (always valid, falls back to the first profile)
+    pub fn seat_profile(&self, seat: usize) -> usize {
+        let index = self.seats.get(seat).copied().unwrap_or(0);
+        if index < self.profiles.len() { index } else { 0 }
+    }
+
+    // create a new profile and return its index (existing one if the name is taken)
+    pub fn add_profile(&mut self, name: &str) -> usize {
+        if let Some(index) = self.profiles.iter().position(|p| p.name == name) {
+            return index;
+        }
+        self.profiles.push(Profile::new(name));
+        self.profiles.len() - 1
+    }
+}
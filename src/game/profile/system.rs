@@ -0,0 +1,61 @@
+use bevy::prelude::*;
+use crate::global::storage;
+use crate::game::profile::resource::{Profile, Profiles, PROFILES_FILE};
+use crate::global::locale::resource::Locale;
+use crate::game::{player::component::Player, round_end::resource::RoundResult, turn_player::component::Turn};
+
+// load profiles from disk at startup
+pub fn load_profiles(mut commands: Commands, locale: Res<Locale>) {
+    let mut profiles: Profiles = storage::load_or_default(PROFILES_FILE);
+
+    // first run (or a broken file): one profile per seat
+    if profiles.profiles.is_empty() {
+        profiles = Profiles {
+            profiles: (1..=2)
+                .map(|seat| Profile::new(&locale.format("profile.default_name", &[("seat", &seat)])))
+                .collect(),
+            seats: [0, 1],
+        };
+    }
+
+    commands.insert_resource(profiles);
+}
+
+pub fn save_profiles(profiles: &Profiles) {
+    storage::save(PROFILES_FILE, profiles);
+}
+
+// add the finished round to each player profile
+pub fn record_round_stats(
+    result: Option<Res<RoundResult>>,
+    turn: Option<Res<Turn>>,
+    player_query: Query<&Player>,
+    mut profiles: ResMut<Profiles>,
+) {
+    let Some(result) = result else { return; };
+    let round_caller = turn.and_then(|t| t.round_caller);
+
+    for (player_entity, _name, score) in result.scores.iter() {
+        let Ok(player) = player_query.get(*player_entity) else { continue; };
+        let Some(profile) = profiles.profiles.get_mut(player.profile) else { continue; };
+
+        let is_winner = result.winner == Some(*player_entity);
+        profile.stats.rounds_played += 1;
+        profile.stats.total_score += score;
+        if is_winner {
+            profile.stats.wins += 1;
+        }
+
+        // player who pressed end round
+        if round_caller == Some(*player_entity) {
+            if is_winner {
+                profile.stats.round_calls_won += 1;
+            } else {
+                profile.stats.round_calls_lost += 1;
+            }
+        }
+    }
+
+    save_profiles(&profiles);
+    info!(target: "mygame", "Profile stats saved");
+}
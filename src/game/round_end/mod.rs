@@ -1,14 +1,20 @@
 use bevy::prelude::*;
-use crate::game::gamestate::AppState;
+use crate::game::gamestate::{AppState, PauseState};
 
 pub mod system;
-use system::{reveal_all_cards, calculate_scores, prepare_new_round};
+pub mod resource;
+use resource::{MatchStandings, NewRoundRequested};
+use system::{calculate_scores, update_standings, reset_standings, prepare_new_round};
 
 pub struct RoundEndPlugin;
 
 impl Plugin for RoundEndPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(AppState::RoundEnd), (reveal_all_cards, calculate_scores).chain())
-        .add_systems(Update, prepare_new_round.run_if(in_state(AppState::RoundEnd)));
+        app.init_resource::<MatchStandings>()
+        .add_message::<NewRoundRequested>()
+        .add_systems(OnEnter(AppState::RoundEnd), (calculate_scores, update_standings).chain())
+        .add_systems(OnEnter(AppState::MainMenu), reset_standings)
+        // restart from the pause menu also works during the turn
+        .add_systems(Update, prepare_new_round.run_if(in_state(AppState::RoundEnd).or(in_state(AppState::PlayerTurn))).run_if(in_state(PauseState::Running)));
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,26 @@
+use bevy::prelude::{Resource, Entity, Message};
+
+// scores of the last finished round (player, name, hand score)
+#[derive(Resource, Default)]
+pub struct RoundResult {
+    pub scores: Vec<(Entity, String, i32)>,
+    pub winner: Option<Entity>,
+}
+
+// totals of the current match, one entry per seat
+#[derive(Resource, Default)]
+pub struct MatchStandings {
+    pub seats: Vec<SeatStanding>,
+}
+
+#[derive(Default, Clone)]
+pub struct SeatStanding {
+    pub name: String,
+    pub total_score: i32,
+    pub wins: u32,
+    pub rounds: u32,
+}
+
+// ask for the next round (same as the NewRound key)
+#[derive(Message)]
+pub struct NewRoundRequested;
@@ -1,30 +1,30 @@
 use bevy::prelude::*;
-use crate::game::{card::component::{Card, CardPosition}, player::component::Player, hand::component::Hand, gamestate::{AppState, GameEntity}, turn_player::component::Turn, graveyard::component::Graveyard, deck::component::Deck};
+use crate::game::{card::component::{Card, CardPosition}, player::component::Player, hand::component::Hand, gamestate::{AppState, GameEntity}, turn_player::component::Turn, graveyard::component::Graveyard, deck::{component::Deck, resource::MatchRules}};
 use crate::game::card::component::{CardBack, CardHandles};
+use crate::game::special_cards::resource::SpecialCardEffect;
+use crate::game::round_end::resource::{RoundResult, MatchStandings, SeatStanding, NewRoundRequested};
+use crate::global::input::{resource::GameAction, system::ActionInput};
 
-// Reveal all cards
-pub fn reveal_all_cards(
-    mut card_query: Query<&mut Card>,
-    player_query: Query<(Entity, &Player)>,
+// Reveal the cards of one player (the results screen goes hand by hand)
+pub fn reveal_hand(
+    player_entity: Entity,
+    card_query: &mut Query<&mut Card>,
 ) {
-    // iterate player cards and if cards are in his hand, face_up = true
-    for (player_entity, _player) in player_query.iter() {
-        for mut card in card_query.iter_mut() {
-            if let CardPosition::Hand(hand_owner) = card.position {
-                if hand_owner == player_entity {
-                    card.face_up = true;
-                    info!(target: "mygame", "Card revealed: {} of {:?}", card.value, card.suit);
-                }
-            }
+    for mut card in card_query.iter_mut() {
+        if card.position == CardPosition::Hand(player_entity) && !card.face_up {
+            card.face_up = true;
+            info!(target: "mygame", "Card revealed: {} of {:?}", card.value, card.suit);
         }
     }
 }
 
 // Calculate scores
 pub fn calculate_scores(
+    mut commands: Commands,
     card_query: Query<&Card>,
     player_query: Query<(Entity, &Player)>,
     hand_query: Query<&Hand>,
+    rules: Res<MatchRules>,
 ) {
     info!(target: "mygame", "=== ROUND END SCORES ===");
     
@@ -36,7 +36,7 @@ pub fn calculate_scores(
             
             for &card_entity in &hand.cards {
                 if let Ok(card) = card_query.get(card_entity) {
-                    total_score += card.value as u32; // sum card value
+                    total_score += rules.score(card.suit, card.value); // sum card points
                 }
             }
             
@@ -46,16 +46,49 @@ pub fn calculate_scores(
     }
     
     // search winner
-    if let Some((_, winner_name, winner_score)) = scores.iter().min_by_key(|(_, _, score)| *score) {
+    let winner = scores.iter().min_by_key(|(_, _, score)| *score);
+    if let Some((_, winner_name, winner_score)) = winner {
         info!(target: "mygame", "WINNER: {} with {} points!", winner_name, winner_score);
     }
+    let winner = winner.map(|(entity, _, _)| *entity);
+
+    commands.insert_resource(RoundResult { scores, winner });
     
     info!(target: "mygame", "Press N for new round");
 }
 
+// add the finished round to the match totals
+pub fn update_standings(
+    result: Res<RoundResult>,
+    player_query: Query<&Player>,
+    mut standings: ResMut<MatchStandings>,
+) {
+    for (player_entity, name, score) in result.scores.iter() {
+        let Ok(player) = player_query.get(*player_entity) else { continue; };
+        if standings.seats.len() <= player.seat {
+            standings.seats.resize(player.seat + 1, SeatStanding::default());
+        }
+
+        let standing = &mut standings.seats[player.seat];
+        standing.name = name.clone();
+        standing.total_score += score;
+        standing.rounds += 1;
+        if result.winner == Some(*player_entity) {
+            standing.wins += 1;
+        }
+    }
+}
+
+// a new match starts from the menu
+pub fn reset_standings(mut standings: ResMut<MatchStandings>) {
+    *standings = MatchStandings::default();
+}
+
 // start new round
+#[allow(clippy::too_many_arguments)]
 pub fn prepare_new_round(
-    keyboard: Res<ButtonInput<KeyCode>>,
+    input: ActionInput,
+    mut requests: MessageReader<NewRoundRequested>,
     mut next_state: ResMut<NextState<AppState>>,
     mut turn: ResMut<Turn>,
     mut commands: Commands,
@@ -64,8 +97,13 @@ pub fn prepare_new_round(
     graveyard_query: Query<Entity, With<Graveyard>>,
     mut hand_query: Query<&mut Hand>,
     player_query: Query<Entity, With<Player>>,
+    state: Res<State<AppState>>,
+    special_effect: Option<ResMut<SpecialCardEffect>>,
 ) {
-    if keyboard.just_pressed(KeyCode::KeyN) {
+    // key at round end, or "Next round"/"Restart round" buttons
+    let requested = requests.read().count() > 0;
+    let key_pressed = *state.get() == AppState::RoundEnd && input.just_pressed(GameAction::NewRound);
+    if key_pressed || requested {
 
         // reset player hands
         for mut hand in hand_query.iter_mut() {
@@ -92,6 +130,12 @@ pub fn prepare_new_round(
         commands.remove_resource::<CardBack>();
 
         turn.has_drawn_card = false;
+        turn.round_caller = None;
+
+        // a restarted round could be choosing a target
+        if let Some(mut effect) = special_effect {
+            *effect = SpecialCardEffect::default();
+        }
         next_state.set(AppState::Setup);
         info!(target: "mygame", "Starting new round...");
     }
@@ -1,15 +1,35 @@
 use bevy::prelude::*;
 use rand::{Rng, seq::SliceRandom};
-use crate::game::{special_cards::resource::{SpecialCardEffect, SpecialEffect}, card::component::{Card, CardPosition}, hand::component::Hand, player::component::Player, turn_player::component::Turn};
+use crate::game::{special_cards::resource::{SpecialCardEffect, SpecialEffect}, card::component::{Card, CardPosition, CARD_SIZE}, hand::component::Hand, player::component::Player, turn_player::component::Turn, table::resource::TableLayout};
 use crate::ui::turn_indicator::component::CardOpacity;
-use crate::ui::card_animation::component::{CardAnimation, AnimationState, AnimationType};
+use crate::ui::card_animation::component::{movement_animation, arc_animation, swirl_animation, MOVEMENT_TIME, SWAP_TIME};
+use crate::ui::tween::component::Ease;
+use crate::ui::soundtrack::resource::CardSound;
+use crate::ui::effect_vfx::component::EffectVfx;
+use crate::global::settings::resource::Settings;
+use crate::ui::toast::component::Toast;
+use crate::global::locale::resource::Locale;
+use crate::game::profile::resource::Profiles;
 
+// count a completed effect in the profile of the player who used it (cancelled ones do not count)
+fn count_effect_used(profiles: &mut Profiles, player_query: &Query<(Entity, &Player)>, current_player: Entity) {
+    if let Ok((_, player)) = player_query.get(current_player)
+        && let Some(profile) = profiles.profiles.get_mut(player.profile) {
+        profile.stats.special_effects_used += 1;
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn reveal_effect(
     special_effect: Option<ResMut<SpecialCardEffect>>,
     mut card_query: Query<&mut Card>,
     hand_query: Query<&Hand>,
     player_query: Query<(Entity, &Player)>,
     turn_query: Res<Turn>,
+    locale: Res<Locale>,
+    mut profiles: ResMut<Profiles>,
+    mut toasts: MessageWriter<Toast>,
+    mut vfx: MessageWriter<EffectVfx>,
 ) {
     // run if effect type is reveal
     let Some(mut special_effect) = special_effect else { return; };
@@ -17,120 +37,131 @@ pub fn reveal_effect(
         return;
     }
     
-    if let Some((_, player)) = player_query.iter().find(|(entity, _)| *entity == turn_query.current_player) {
-        if let Ok(hand) = hand_query.get(player.hand) {
+    if let Some((_, player)) = player_query.iter().find(|(entity, _)| *entity == turn_query.current_player)
+        && let Ok(hand) = hand_query.get(player.hand) {
             
-            // find one random card with face_up = false to return true
-            let face_down_cards: Vec<Entity> = hand.cards.iter()
-                .filter_map(|&card_entity| {
-                    card_query.get(card_entity).ok()
-                        .filter(|card| !card.face_up)
-                        .map(|_| card_entity)
-                })
-                .collect();
-
-            if !face_down_cards.is_empty() {
-                let mut rng = rand::rng();
-                let random_index = rng.random_range(0..face_down_cards.len());
-                let selected_card = face_down_cards[random_index];
+        // find one random card with face_up = false to return true
+        let face_down_cards: Vec<Entity> = hand.cards.iter()
+            .filter_map(|&card_entity| {
+                card_query.get(card_entity).ok()
+                    .filter(|card| !card.face_up)
+                    .map(|_| card_entity)
+            })
+            .collect();
+
+        if !face_down_cards.is_empty() {
+            let mut rng = rand::rng();
+            let random_index = rng.random_range(0..face_down_cards.len());
+            let selected_card = face_down_cards[random_index];
                 
-                if let Ok(mut card) = card_query.get_mut(selected_card) {
-                    card.face_up = true;
-                    info!(target: "mygame", "Revealed card: {} of {:?}", card.value, card.suit);
-                }
+            if let Ok(mut card) = card_query.get_mut(selected_card) {
+                card.face_up = true;
+                vfx.write(EffectVfx::Reveal(selected_card));
+                info!(target: "mygame", "Revealed card: {} of {:?}", card.value, card.suit);
+                toasts.write(Toast::info(locale.format("toast.revealed", &[("card", &locale.card_name(card.value, card.suit))])));
             }
+        }
 
-            // reset from_deck so that the effect is used only once
-            if let Some(special_card_entity) = special_effect.card_entity {
-                if let Ok(mut special_card) = card_query.get_mut(special_card_entity) {
-                    special_card.from_deck = false;
-                }
-            }
+        // reset from_deck so that the effect is used only once
+        if let Some(special_card_entity) = special_effect.card_entity
+            && let Ok(mut special_card) = card_query.get_mut(special_card_entity) {
+            special_card.from_deck = false;
         }
+        count_effect_used(&mut profiles, &player_query, turn_query.current_player);
     }
     // clean effect
     *special_effect = SpecialCardEffect::default();
     info!(target: "mygame", "Effect completed");
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn shuffle_effect(
+    mut commands: Commands,
     special_effect: Option<ResMut<SpecialCardEffect>>,
     mut card_query: Query<(Entity, &mut Transform, &mut Card), With<Card>>,
-    hand_query: Query<&Hand>,
+    mut hand_query: Query<&mut Hand>,
     player_query: Query<(Entity, &Player)>,
+    turn_query: Res<Turn>,
+    layout: Res<TableLayout>,
+    settings: Res<Settings>,
+    mut profiles: ResMut<Profiles>,
+    mut sounds: MessageWriter<CardSound>,
+    mut vfx: MessageWriter<EffectVfx>,
 ) {
     // run if effect type is shuffle
-    if let Some(mut effect) = special_effect {
-        if matches!(effect.effect_type, Some(SpecialEffect::Shuffle)) {
+    if let Some(mut effect) = special_effect
+        && matches!(effect.effect_type, Some(SpecialEffect::Shuffle)) {
             
-            // verify do not expecting target
-            if effect.awaiting_target { return; }
-
-            // verify have target_player
-            let Some(target_player_id) = effect.target_player else {
-                *effect = SpecialCardEffect::default();
-                return;
-            };
-
-            // find target player
-            let target_player = player_query.iter()
-                .find(|(entity, _)| *entity == target_player_id);
+        // verify do not expecting target
+        if effect.awaiting_target { return; }
+
+        // verify have target_player
+        let Some(target_player_id) = effect.target_player else {
+            *effect = SpecialCardEffect::default();
+            return;
+        };
+
+        // find target player
+        let target_player = player_query.iter()
+            .find(|(entity, _)| *entity == target_player_id);
     
-            let Some((_, player)) = target_player else {
-                info!(target: "mygame", "Target player not found");
-                *effect = SpecialCardEffect::default();
-                return;
-            };
-
-            // find target player hand
-            let Ok(hand) = hand_query.get(player.hand) else {
-                info!(target: "mygame", "Target player hand not found");
-                *effect = SpecialCardEffect::default();
-                return;
-            };
-
-            // verify if have 4 cards in hand
-            if hand.cards.len() != 4 {
-                info!(target: "mygame", "Target player doesn't have 4 cards");
-                *effect = SpecialCardEffect::default();
-                return;
-            };
-
-            // verify card positions
-            let mut positions: Vec<Vec3> = Vec::new();
-            for &card_entity in &hand.cards {
-                if let Ok((_, transform, mut card)) = card_query.get_mut(card_entity) {
-                    positions.push(transform.translation); // push to Vec (positions)
-                    card.face_up = false;
-                }
-            }
-            
-            // randomize positions
-            let mut rng = rand::rng();
-            positions.shuffle(&mut rng);
+        let Some((_, player)) = target_player else {
+            info!(target: "mygame", "Target player not found");
+            *effect = SpecialCardEffect::default();
+            return;
+        };
 
-            // update card positions
-            for (i, &card_entity) in hand.cards.iter().enumerate() {
-               if let Ok((_, mut transform, _)) = card_query.get_mut(card_entity) {
-                   transform.translation = positions[i];
-               }
-            }
+        // find target player hand
+        let Ok(mut hand) = hand_query.get_mut(player.hand) else {
+            info!(target: "mygame", "Target player hand not found");
+            *effect = SpecialCardEffect::default();
+            return;
+        };
 
-            // reset from_deck so that the effect is used only once
-            if let Some(special_card_entity) = effect.card_entity {
-                if let Ok(mut special_card) = card_query.get_mut(special_card_entity) {
-                    special_card.2.from_deck = false;
+        // verify if have 4 cards in hand
+        if hand.cards.len() != 4 {
+            info!(target: "mygame", "Target player doesn't have 4 cards");
+            *effect = SpecialCardEffect::default();
+            return;
+        };
+
+        // randomize slots
+        let mut rng = rand::rng();
+        hand.cards.shuffle(&mut rng);
+
+        // update card positions, swirling around the hand unless motion is reduced
+        let positions = layout.hand_positions(player.seat);
+        let center = positions.iter().sum::<Vec3>() / positions.len() as f32;
+        let radius = CARD_SIZE.x * layout.card_scale().x * 0.6;
+        for (slot, &card_entity) in hand.cards.iter().enumerate() {
+            if let Ok((_, mut transform, mut card)) = card_query.get_mut(card_entity) {
+                card.face_up = false;
+                if settings.reduced_motion {
+                    transform.translation = positions[slot];
+                } else {
+                    // the card stays in its origin, the animation ends in the new slot
+                    commands.entity(card_entity).insert(swirl_animation(transform.translation, positions[slot], center, radius, transform.rotation));
                 }
             }
+        }
+        vfx.write(EffectVfx::Shuffle(center));
 
-            info!(target: "mygame", "Cards shuffled for target player!");
-
-            *effect = SpecialCardEffect::default();
-            info!(target: "mygame", "Effect completed");
+        // reset from_deck so that the effect is used only once
+        if let Some(special_card_entity) = effect.card_entity
+            && let Ok(mut special_card) = card_query.get_mut(special_card_entity) {
+            special_card.2.from_deck = false;
         }
+        count_effect_used(&mut profiles, &player_query, turn_query.current_player);
+
+        sounds.write(CardSound::Shuffle);
+        info!(target: "mygame", "Cards shuffled for target player!");
+
+        *effect = SpecialCardEffect::default();
+        info!(target: "mygame", "Effect completed");
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn swap_effect(
     mut commands: Commands,
     special_effect: Option<ResMut<SpecialCardEffect>>,
@@ -138,117 +169,103 @@ pub fn swap_effect(
     mut hand_query: Query<&mut Hand>,
     player_query: Query<(Entity, &Player)>,
     turn_query: Res<Turn>,
+    settings: Res<Settings>,
+    mut profiles: ResMut<Profiles>,
+    mut sounds: MessageWriter<CardSound>,
+    mut vfx: MessageWriter<EffectVfx>,
 ) {
     // run if effect type is swap
-    if let Some(mut effect) = special_effect {
-        if matches!(effect.effect_type, Some(SpecialEffect::Swap)) {
+    if let Some(mut effect) = special_effect
+        && matches!(effect.effect_type, Some(SpecialEffect::Swap)) {
             
-            // verify if have target_card (rival card) and own_card (own card)
-            if let (Some(target_card_entity), Some(own_card_entity)) = (effect.target_card, effect.own_card) {
-                if let Ok([(_, mut target_transform, mut target_card, target_opacity), (_, mut own_transform, mut own_card, own_opacity)]) =
-                    card_query.get_many_mut([target_card_entity, own_card_entity]) {
+        // verify if have target_card (rival card) and own_card (own card)
+        if let (Some(target_card_entity), Some(own_card_entity)) = (effect.target_card, effect.own_card) {
+            if let Ok([(_, mut target_transform, mut target_card, target_opacity), (_, mut own_transform, mut own_card, own_opacity)]) =
+                card_query.get_many_mut([target_card_entity, own_card_entity]) {
                     
-                    // obtain positions and owner id
-                    let target_pos = target_transform.translation;
-                    let own_pos = own_transform.translation;
-                    let target_id = target_card.owner_id;
-                    let own_id = own_card.owner_id;
-
-                    // swap and update positions, owner ids and no face_up
-                    target_transform.translation = own_pos;
-                    target_card.owner_id = own_id;
-                    target_card.position = CardPosition::Hand(own_id.unwrap());
-                    target_card.face_up = false;
-                    own_transform.translation = target_pos;
-                    own_card.owner_id = target_id;
-                    own_card.position = CardPosition::Hand(target_id.unwrap());
-                    own_card.face_up = false;
-
-                    // insert movement card animation
-                    commands.entity(target_card_entity).insert(CardAnimation {
-                        animation_type: AnimationType::Movement,
-                        progress: 0.0,
-                        duration: 0.25,
-                        state: AnimationState::Animating,
-                        original_position: target_pos,  // previous position
-                        original_scale: target_transform.scale,
-                        original_rotation: target_transform.rotation,
-                        target_position: Some(own_pos),  // new position
-                        delay: 0.0,
-                        delay_elapsed: 0.0,
-                    });
-
-                    // reset position for start from origin
-                    target_transform.translation = target_pos;
-
-                    commands.entity(own_card_entity).insert(CardAnimation {
-                        animation_type: AnimationType::Movement,
-                        progress: 0.0,
-                        duration: 0.25,
-                        state: AnimationState::Animating,
-                        original_position: own_pos,
-                        original_scale: own_transform.scale,
-                        original_rotation: own_transform.rotation,
-                        target_position: Some(target_pos),
-                        delay: 0.0,
-                        delay_elapsed: 0.0,
-                    });
-
-                    // reset position for start from origin
-                    own_transform.translation = own_pos;
-
-                    // update and save changes of player hand with target_card
-                    if let Some((_, target_player)) = player_query.iter().find(|(entity, _)| Some(*entity) == target_id) {
-                        if let Ok(mut target_hand) = hand_query.get_mut(target_player.hand) {
-                            // remove target_card => add own_card
-                            target_hand.cards.retain(|&card_entity| card_entity != target_card_entity);
-                            target_hand.cards.push(own_card_entity);
-                        }
-                    }
+                // obtain positions and owner id
+                let target_pos = target_transform.translation;
+                let own_pos = own_transform.translation;
+                let target_id = target_card.owner_id;
+                let own_id = own_card.owner_id;
 
-                    // update and save changes of player hand with own_card
-                    if let Some((_, own_player)) = player_query.iter().find(|(entity, _)| Some(*entity) == own_id) {
-                        if let Ok(mut own_hand) = hand_query.get_mut(own_player.hand) {
-                            // remove own_card => add target_card
-                            own_hand.cards.retain(|&card_entity| card_entity != own_card_entity);
-                            own_hand.cards.push(target_card_entity);
-                        }
-                    }
+                // swap and update positions, owner ids and no face_up
+                target_transform.translation = own_pos;
+                target_card.owner_id = own_id;
+                target_card.position = CardPosition::Hand(own_id.unwrap());
+                target_card.face_up = false;
+                own_transform.translation = target_pos;
+                own_card.owner_id = target_id;
+                own_card.position = CardPosition::Hand(target_id.unwrap());
+                own_card.face_up = false;
+
+                // insert movement card animation, crossing arcs unless motion is reduced
+                let swap_animation = |from, to| if settings.reduced_motion {
+                    movement_animation(from, to, MOVEMENT_TIME, Ease::QuadInOut)
+                } else {
+                    arc_animation(from, to, SWAP_TIME)
+                };
+                commands.entity(target_card_entity).insert(swap_animation(target_pos, own_pos));
+
+                // reset position for start from origin
+                target_transform.translation = target_pos;
 
-                    // Actualizar opacidad de target_card
-                    if let Some(mut target_opacity) = target_opacity {
-                        if let CardPosition::Hand(owner) = target_card.position {
-                            let new_value = if owner == turn_query.current_player { 1.0 } else { 0.2 };
-                            target_opacity.current = new_value;
-                            target_opacity.target = new_value;
-                        }
+                commands.entity(own_card_entity).insert(swap_animation(own_pos, target_pos));
+
+                // reset position for start from origin
+                own_transform.translation = own_pos;
+
+                // update and save changes of player hand with target_card
+                if let Some((_, target_player)) = player_query.iter().find(|(entity, _)| Some(*entity) == target_id)
+                    && let Ok(mut target_hand) = hand_query.get_mut(target_player.hand) {
+                    // own_card takes the slot of target_card
+                    if let Some(slot) = target_hand.cards.iter().position(|&card_entity| card_entity == target_card_entity) {
+                        target_hand.cards[slot] = own_card_entity;
                     }
+                }
 
-                    // Actualizar opacidad de own_card  
-                    if let Some(mut own_opacity) = own_opacity {
-                        if let CardPosition::Hand(owner) = own_card.position {
-                            let new_value = if owner == turn_query.current_player { 1.0 } else { 0.2 };
-                            own_opacity.current = new_value;
-                            own_opacity.target = new_value;
-                        }
+                // update and save changes of player hand with own_card
+                if let Some((_, own_player)) = player_query.iter().find(|(entity, _)| Some(*entity) == own_id)
+                    && let Ok(mut own_hand) = hand_query.get_mut(own_player.hand) {
+                    // target_card takes the slot of own_card
+                    if let Some(slot) = own_hand.cards.iter().position(|&card_entity| card_entity == own_card_entity) {
+                        own_hand.cards[slot] = target_card_entity;
                     }
-                    
-                    info!(target: "mygame", "Card swap completed: {:?} -> {:?}", target_card_entity, own_card_entity);
                 }
-            } else {
-                info!(target: "mygame", "One more card needs to be selected");
-                return;
-            }
 
-            // reset from_deck so that the effect is used only once
-            if let Some(special_card_entity) = effect.card_entity {
-                if let Ok((_, _, mut special_card, _)) = card_query.get_mut(special_card_entity) {
-                    special_card.from_deck = false;
+                // Actualizar opacidad de target_card
+                if let Some(mut target_opacity) = target_opacity
+                    && let CardPosition::Hand(owner) = target_card.position {
+                    let new_value = if owner == turn_query.current_player { 1.0 } else { 0.2 };
+                    target_opacity.current = new_value;
+                    target_opacity.target = new_value;
                 }
+
+                // Actualizar opacidad de own_card  
+                if let Some(mut own_opacity) = own_opacity
+                    && let CardPosition::Hand(owner) = own_card.position {
+                    let new_value = if owner == turn_query.current_player { 1.0 } else { 0.2 };
+                    own_opacity.current = new_value;
+                    own_opacity.target = new_value;
+                }
+                    
+                sounds.write(CardSound::Swap);
+                vfx.write(EffectVfx::Swap(target_pos, own_pos));
+                info!(target: "mygame", "Card swap completed: {:?} -> {:?}", target_card_entity, own_card_entity);
             }
+        } else {
+            info!(target: "mygame", "One more card needs to be selected");
+            return;
+        }
 
-            *effect = SpecialCardEffect::default();
-            info!(target: "mygame", "Effect completed");
+        // reset from_deck so that the effect is used only once
+        if let Some(special_card_entity) = effect.card_entity
+            && let Ok((_, _, mut special_card, _)) = card_query.get_mut(special_card_entity) {
+            special_card.from_deck = false;
         }
+        count_effect_used(&mut profiles, &player_query, turn_query.current_player);
+
+        *effect = SpecialCardEffect::default();
+        info!(target: "mygame", "Effect completed");
     }
 }
\ No newline at end of file
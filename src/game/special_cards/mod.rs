@@ -1,21 +1,26 @@
 use bevy::prelude::*;
-use crate::game::gamestate::AppState;
+use crate::game::gamestate::{AppState, PauseState};
+use crate::game::turn_player::system::turn_confirmed;
+use crate::game::action_queue::system::board_idle;
 
 pub mod resource;
 mod effect;
 mod system;
-use system::{detect_special_card, handle_special_effects};
+use resource::CancelSpecialEffect;
+use system::{detect_special_card, handle_special_effects, cancel_special_effect};
 use effect::{reveal_effect, shuffle_effect, swap_effect};
 pub struct SpecialCardsPlugin;
 
 impl Plugin for SpecialCardsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, (
-            detect_special_card,
+        app.add_message::<CancelSpecialEffect>()
+        .add_systems(Update, (
+            detect_special_card.run_if(turn_confirmed).run_if(board_idle),
+            cancel_special_effect.run_if(turn_confirmed),
             handle_special_effects,
             reveal_effect,
             shuffle_effect,
             swap_effect,
-        ).run_if(in_state(AppState::PlayerTurn)));
+        ).run_if(in_state(AppState::PlayerTurn)).run_if(in_state(PauseState::Running)));
     }
 }
\ No newline at end of file
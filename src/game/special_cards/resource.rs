@@ -1,4 +1,5 @@
-use bevy::prelude::{Resource, Entity};
+use bevy::prelude::{Resource, Entity, Message};
+use crate::game::card::component::{Card, CardPosition};
 
 #[derive(Resource, Default)]
 pub struct SpecialCardEffect {
@@ -11,9 +12,30 @@ pub struct SpecialCardEffect {
     pub own_card: Option<Entity>
 }
 
-#[derive(Debug, Clone)]
+impl SpecialCardEffect {
+    // waiting for the player to pick a card
+    pub fn is_targeting(&self) -> bool {
+        self.awaiting_target || self.awaiting_own_card
+    }
+
+    // card that can be picked now: a rival card first, then one of your own (swap)
+    pub fn is_valid_target(&self, card: &Card, current_player: Entity) -> bool {
+        match card.position {
+            CardPosition::Hand(owner) if self.awaiting_target => owner != current_player,
+            CardPosition::Hand(owner) if self.awaiting_own_card => owner == current_player,
+            _ => false,
+        }
+    }
+}
+
+// skip the effect being targeted (Cancel key or button)
+#[derive(Message)]
+pub struct CancelSpecialEffect;
+
+// cards of each effect depend on the deck, see DeckType::special_candidates
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum SpecialEffect {
-    Shuffle,    // card 11
-    Reveal,     // card 9
-    Swap,       // card 7
+    Shuffle,    // spanish 11
+    Reveal,     // spanish 9
+    Swap,       // spanish 7
 }
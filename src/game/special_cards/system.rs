@@ -1,21 +1,31 @@
 use bevy::prelude::*;
 use crate::game::{card::component::{Card, CardPosition}, turn_player::component::Turn};
-use crate::game::special_cards::resource::{SpecialCardEffect, SpecialEffect};
+use crate::game::special_cards::resource::{SpecialCardEffect, SpecialEffect, CancelSpecialEffect};
+use crate::game::deck::resource::MatchRules;
+use crate::global::input::{resource::GameAction, system::ActionInput};
+use crate::ui::toast::component::Toast;
+use crate::ui::soundtrack::resource::CardSound;
+use crate::ui::effect_vfx::component::EffectVfx;
 
+#[allow(clippy::too_many_arguments)]
 pub fn detect_special_card(
     mut commands: Commands,
     card_query: Query<(Entity, &Card)>,
-    keyboard: Res<ButtonInput<KeyCode>>,
+    input: ActionInput,
     turn_query: Res<Turn>,
     special_effect: Option<Res<SpecialCardEffect>>,
+    rules: Res<MatchRules>,
+    mut toasts: MessageWriter<Toast>,
+    mut sounds: MessageWriter<CardSound>,
+    mut vfx: MessageWriter<EffectVfx>,
 ) {
-    // verify if have effect
-    if special_effect.as_ref().map_or(false, |s| s.awaiting_target) {
+    // an effect is already running (targeting or about to resolve)
+    if special_effect.as_ref().is_some_and(|s| s.effect_type.is_some()) {
         return;
     }
 
     // verify if the key was pressed to activate special card
-    if !keyboard.just_pressed(KeyCode::KeyE) { return; }
+    if !input.just_pressed(GameAction::ActivateSpecial) { return; }
 
     // obtain current drawn card
     let drawn_card = card_query.iter()
@@ -26,13 +36,8 @@ pub fn detect_special_card(
         });
 
     if let Some((card_entity, card)) = drawn_card {
-        // match if drawn card is special card
-        let special_effect_type = match card.value {
-            11 => Some(SpecialEffect::Shuffle),
-            9 => Some(SpecialEffect::Reveal), 
-            7 => Some(SpecialEffect::Swap),
-            _ => None,
-        };
+        // match if drawn card is special card (depends on the deck)
+        let special_effect_type = rules.special(card.suit, card.value);
         
         if let Some(effect) = special_effect_type {
             info!(target: "mygame", "Special card {} activated! Effect: {:?}", card.value, effect);
@@ -40,7 +45,7 @@ pub fn detect_special_card(
             // create/update special effect
             let new_effect = SpecialCardEffect {
                 card_entity: Some(card_entity),
-                effect_type: Some(effect.clone()),
+                effect_type: Some(effect),
                 awaiting_target: matches!(effect, SpecialEffect::Shuffle | SpecialEffect::Swap),
                 target_player: None,
                 target_card: None,
@@ -48,10 +53,13 @@ pub fn detect_special_card(
                 own_card: None
             };
             commands.insert_resource(new_effect);
+            sounds.write(CardSound::Effect);
+            vfx.write(EffectVfx::Activated(card_entity, effect));
             return;
         }
     } else {
         info!(target: "mygame", "No special cards from deck available (cards from graveyard lose their effect)");
+        toasts.write(Toast::info("toast.no_special"));
     }
     info!(target: "mygame", "No face-up special cards available");
 }
@@ -60,23 +68,39 @@ pub fn handle_special_effects(
     special_effect: Option<ResMut<SpecialCardEffect>>,
 ) {
     // run if resource exist
-    if let Some(effect) = special_effect {
-        if let Some(effect_type) = &effect.effect_type.clone() {
-            match effect_type {
-                SpecialEffect::Reveal => {
-                    info!(target: "mygame", "Reveal effect active");
-                },
-                SpecialEffect::Shuffle => {
-                    if effect.awaiting_target {
-                        info!(target: "mygame", "Waiting for target selection for shuffle effect...");
-                    }
-                },
-                SpecialEffect::Swap => {
-                    if effect.awaiting_target {
-                        info!(target: "mygame", "Waiting for target selection for swap effect...");
-                    }
-                },
-            }
+    if let Some(effect) = special_effect
+        && let Some(effect_type) = &effect.effect_type.clone() {
+        match effect_type {
+            SpecialEffect::Reveal => {
+                info!(target: "mygame", "Reveal effect active");
+            },
+            SpecialEffect::Shuffle => {
+                if effect.awaiting_target {
+                    info!(target: "mygame", "Waiting for target selection for shuffle effect...");
+                }
+            },
+            SpecialEffect::Swap => {
+                if effect.awaiting_target {
+                    info!(target: "mygame", "Waiting for target selection for swap effect...");
+                }
+            },
         }
     }
-}
\ No newline at end of file
+}
+// skip the effect while choosing targets, the card keeps its effect
+pub fn cancel_special_effect(
+    input: ActionInput,
+    mut requests: MessageReader<CancelSpecialEffect>,
+    special_effect: Option<ResMut<SpecialCardEffect>>,
+) {
+    let requested = requests.read().count() > 0;
+    let Some(mut effect) = special_effect else { return; };
+    if !effect.is_targeting() {
+        return;
+    }
+
+    if requested || input.just_pressed(GameAction::Cancel) {
+        *effect = SpecialCardEffect::default();
+        info!(target: "mygame", "Special effect cancelled");
+    }
+}
@@ -0,0 +1,5 @@
+use bevy::prelude::*;
+
+// card that was animating when the layout changed, placed again once it lands
+#[derive(Component)]
+pub struct LayoutPending;
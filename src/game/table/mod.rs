@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+
+pub mod component;
+pub mod resource;
+pub mod system;
+
+use crate::game::table::resource::TableLayout;
+use crate::game::table::system::{update_table_layout, apply_table_layout, apply_pending_layout};
+
+pub struct TablePlugin;
+
+impl Plugin for TablePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<TableLayout>()
+            .add_systems(PreUpdate, update_table_layout)
+            .add_systems(Update, (
+                apply_table_layout.run_if(resource_changed::<TableLayout>),
+                apply_pending_layout,
+            ).chain());
+    }
+}
@@ -0,0 +1,111 @@
+use bevy::prelude::*;
+use crate::game::card::component::CARD_SIZE;
+
+// window size the table proportions were designed for
+pub const REFERENCE_SIZE: Vec2 = Vec2::new(1280.0, 720.0);
+pub const CARD_SCALE: f32 = 0.7; // card scale at the reference size
+pub const LARGE_CARD_SCALE: f32 = 1.25; // on top of the card scale, accessibility setting
+pub const CARD_THICKNESS: Vec2 = Vec2::new(0.15, 0.12); // offset each card adds to its pile
+
+// positions of every zone and seat of the table, computed from the window size;
+// seats are fixed (bottom, top, left, right), a game with 2 to 4 players takes the first ones
+#[derive(Resource)]
+pub struct TableLayout {
+    pub size: Vec2,
+    pub large_cards: bool,
+}
+
+impl Default for TableLayout {
+    fn default() -> Self {
+        Self {
+            size: REFERENCE_SIZE,
+            large_cards: false,
+        }
+    }
+}
+
+impl TableLayout {
+    // relative to the reference size, so the table keeps its proportions
+    pub fn zoom(&self) -> f32 {
+        (self.size.x / REFERENCE_SIZE.x).min(self.size.y / REFERENCE_SIZE.y)
+    }
+
+    pub fn card_scale(&self) -> Vec3 {
+        let large = if self.large_cards { LARGE_CARD_SCALE } else { 1.0 };
+        Vec3::splat(CARD_SCALE * self.zoom() * large)
+    }
+
+    // DECK
+    pub fn deck_position(&self) -> Vec3 {
+        Vec3::new(self.size.x * 0.15, 0.0, 20.0)
+    }
+
+    // depth counts from the bottom card of the pile, so the pile gets lower as it is drawn
+    pub fn deck_stack_position(&self, depth: usize) -> Vec3 {
+        let stack = CARD_THICKNESS * depth as f32 * self.zoom();
+        Vec3::new(self.size.x * 0.15 + stack.x, stack.y, depth as f32)
+    }
+
+    // GRAVEYARD
+    pub fn graveyard_position(&self) -> Vec3 {
+        Vec3::new(self.size.x * -0.06, 0.0, 5.0)
+    }
+
+    // grows with every discard, each card a bit out of place (always the same for a depth, so relayouts keep it)
+    pub fn graveyard_stack_position(&self, depth: usize) -> Vec3 {
+        let hash = (depth as u32 + 1).wrapping_mul(2654435761);
+        let scatter = Vec2::new((hash >> 8) as f32 % 9.0 - 4.0, (hash >> 16) as f32 % 7.0 - 3.0);
+        let stack = (CARD_THICKNESS * depth as f32 + scatter) * self.zoom();
+        Vec3::new(self.size.x * -0.06 + stack.x, stack.y, 10.0 + depth as f32)
+    }
+
+    // DRAWN CARD
+    pub fn drawn_card_position(&self) -> Vec3 {
+        Vec3::new(self.size.x * 0.1, 0.0, 30.0)
+    }
+
+    // HANDS
+    // seat 0 = bottom (local player), 1 = top, 2 = left, 3 = right
+    pub fn hand_position(&self, seat: usize, slot: usize) -> Vec3 {
+        let z = 10.0 + slot as f32;
+        match seat {
+            1 => Vec3::new(self.size.x * -0.06 + self.size.x * 0.06 * slot as f32, self.size.y * 0.15, z),
+            2 => Vec3::new(self.size.x * -0.35, self.size.y * 0.15 - self.size.y * 0.1 * slot as f32, z),
+            3 => Vec3::new(self.size.x * 0.35, self.size.y * 0.15 - self.size.y * 0.1 * slot as f32, z),
+            _ => Vec3::new(self.size.x * -0.06 + self.size.x * 0.06 * slot as f32, self.size.y * -0.15, z),
+        }
+    }
+
+    pub fn hand_positions(&self, seat: usize) -> [Vec3; 4] {
+        std::array::from_fn(|slot| self.hand_position(seat, slot))
+    }
+
+    // selected cards move a bit toward the center of the table
+    pub fn selected_offset(&self, seat: usize) -> Vec3 {
+        let lift = self.size.y * 0.02;
+        match seat {
+            1 => Vec3::new(0.0, -lift, 0.0),
+            2 => Vec3::new(lift, 0.0, 0.0),
+            3 => Vec3::new(-lift, 0.0, 0.0),
+            _ => Vec3::new(0.0, lift, 0.0),
+        }
+    }
+
+    // UI
+    // cards left, under the bottom edge of each pile
+    pub fn deck_counter_position(&self) -> Vec3 {
+        self.deck_position().with_y(-self.counter_offset()).with_z(200.0)
+    }
+
+    pub fn graveyard_counter_position(&self) -> Vec3 {
+        self.graveyard_position().with_y(-self.counter_offset()).with_z(200.0)
+    }
+
+    fn counter_offset(&self) -> f32 {
+        CARD_SIZE.y * self.card_scale().y / 2.0 + 14.0 * self.zoom()
+    }
+
+    pub fn back_button_position(&self) -> Vec3 {
+        Vec3::new(self.size.x * -0.44, self.size.y * 0.4, 10.0)
+    }
+}
@@ -0,0 +1,106 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use crate::game::card::component::{Card, CardDrag, CardPosition};
+use crate::game::{deck::component::Deck, graveyard::component::Graveyard, hand::component::Hand, player::component::Player};
+use crate::game::table::{component::LayoutPending, resource::TableLayout};
+use crate::global::settings::resource::Settings;
+use crate::ui::back_button::component::BackButton;
+use crate::ui::card_animation::component::CardAnimation;
+
+// follow the window size and the large cards setting
+pub fn update_table_layout(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    settings: Res<Settings>,
+    mut layout: ResMut<TableLayout>,
+) {
+    let Ok(window) = windows.single() else { return; };
+    let size = Vec2::new(window.width(), window.height());
+
+    // only write on changes, other systems react to it
+    if layout.size != size || layout.large_cards != settings.large_cards {
+        layout.size = size;
+        layout.large_cards = settings.large_cards;
+    }
+}
+
+// move every card of the table to its zone after a layout change
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn apply_table_layout(
+    mut commands: Commands,
+    layout: Res<TableLayout>,
+    animating_query: Query<Entity, (With<Card>, With<CardAnimation>)>,
+    mut card_query: Query<(Entity, &mut Transform, &Card), Without<CardAnimation>>,
+    player_query: Query<&Player>,
+    hand_query: Query<&Hand>,
+    deck_query: Query<&Deck>,
+    mut graveyard_query: Query<(&Graveyard, &mut Transform), Without<Card>>,
+    mut back_button_query: Query<&mut Transform, (With<BackButton>, Without<Card>, Without<Graveyard>)>,
+    drag: Res<CardDrag>,
+) {
+    // cards in the air keep their old target, they are placed when they land
+    for entity in animating_query.iter() {
+        commands.entity(entity).insert(LayoutPending);
+    }
+
+    // hands
+    for player in player_query.iter() {
+        let Ok(hand) = hand_query.get(player.hand) else { continue; };
+        for (slot, &card_entity) in hand.cards.iter().enumerate() {
+            if let Ok((_, mut transform, _)) = card_query.get_mut(card_entity) {
+                transform.translation = layout.hand_position(player.seat, slot);
+            }
+        }
+    }
+
+    // deck, first card on top
+    if let Ok(deck) = deck_query.single() {
+        let count = deck.cards_values.len();
+        for (i, &card_entity) in deck.cards_values.iter().enumerate() {
+            if let Ok((_, mut transform, _)) = card_query.get_mut(card_entity) {
+                transform.translation = layout.deck_stack_position(count - 1 - i);
+            }
+        }
+    }
+
+    // graveyard, last card on top
+    if let Ok((graveyard, mut graveyard_transform)) = graveyard_query.single_mut() {
+        graveyard_transform.translation = layout.graveyard_position();
+        graveyard_transform.scale = layout.card_scale();
+        for (depth, &card_entity) in graveyard.cards.iter().enumerate() {
+            if let Ok((_, mut transform, _)) = card_query.get_mut(card_entity) {
+                transform.translation = layout.graveyard_stack_position(depth);
+            }
+        }
+    }
+
+    for (entity, mut transform, card) in card_query.iter_mut() {
+        // the dragged card keeps following the cursor
+        if drag.entity == Some(entity) {
+            continue;
+        }
+        if matches!(card.position, CardPosition::DrawnCard(_)) {
+            transform.translation = layout.drawn_card_position();
+        }
+        transform.scale = layout.card_scale();
+    }
+
+    if let Ok(mut transform) = back_button_query.single_mut() {
+        transform.translation = layout.back_button_position();
+        transform.scale = Vec3::splat(0.7 * layout.zoom());
+    }
+}
+
+// a card skipped by the last layout change has landed: apply the layout again
+pub fn apply_pending_layout(
+    mut commands: Commands,
+    pending_query: Query<Entity, (With<LayoutPending>, Without<CardAnimation>)>,
+    mut layout: ResMut<TableLayout>,
+) {
+    if pending_query.is_empty() {
+        return;
+    }
+    for entity in pending_query.iter() {
+        commands.entity(entity).remove::<LayoutPending>();
+    }
+    layout.set_changed();
+}
@@ -1,7 +1,39 @@
 use bevy::prelude::*;
+use crate::game::card::component::{Card, CardPosition};
 
 #[derive(Resource)]
 pub struct Turn {
     pub current_player: Entity,
     pub has_drawn_card: bool,
-}
\ No newline at end of file
+    pub number: u32, // turns played this round, from 1
+    pub round_caller: Option<Entity>, // player who ended the round
+}
+
+// hot-seat mode: both players share the screen, so private cards stay hidden
+// until the next player confirms the handoff (off by default, see the hot_seat setting)
+#[derive(Resource, Default)]
+pub struct HotSeat {
+    pub enabled: bool,
+    pub confirmed_player: Option<Entity>, // last player that confirmed the handoff
+}
+
+impl HotSeat {
+    // true while the current player has not confirmed the handoff yet
+    pub fn is_pending(&self, turn: &Turn) -> bool {
+        self.enabled && self.confirmed_player != Some(turn.current_player)
+    }
+
+    // verify if a face up card can be shown to the player sitting at the screen
+    pub fn can_show(&self, card: &Card, turn: &Turn) -> bool {
+        if !self.enabled {
+            return true;
+        }
+
+        match card.position {
+            CardPosition::Hand(owner) | CardPosition::DrawnCard(owner) => {
+                owner == turn.current_player && !self.is_pending(turn)
+            }
+            _ => true, // deck and graveyard are public
+        }
+    }
+}
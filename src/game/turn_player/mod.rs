@@ -1,10 +1,11 @@
 use bevy::prelude::*;
-use crate::game::gamestate::AppState;
+use crate::game::gamestate::{AppState, PauseState};
 
 pub mod component;
-mod system;
+pub mod system;
 
-use system::{start_turn_system, end_round_system};
+use component::HotSeat;
+use system::{start_turn_system, end_round_system, turn_confirmed};
 use crate::game::player::system::spawn_player;
 
 pub struct TurnPlugin;
@@ -12,7 +13,8 @@ pub struct TurnPlugin;
 impl Plugin for TurnPlugin {
     fn build(&self, app: &mut App) {
         app
+            .init_resource::<HotSeat>()
             .add_systems(OnEnter(AppState::Setup), (spawn_player, start_turn_system).chain())
-            .add_systems(Update, end_round_system.run_if(in_state(AppState::PlayerTurn)));
+            .add_systems(Update, end_round_system.run_if(in_state(AppState::PlayerTurn)).run_if(turn_confirmed).run_if(in_state(PauseState::Running)));
     }
-}
\ No newline at end of file
+}
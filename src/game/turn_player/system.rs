@@ -1,6 +1,8 @@
 use bevy::prelude::*;
 use crate::game::{player::component::Player, gamestate::AppState};
-use super::component::Turn;
+use crate::game::action_queue::resource::{ActionQueue, QueuedAction};
+use super::component::{Turn, HotSeat};
+use crate::global::input::{resource::GameAction, system::ActionInput};
 
 // start first player turn
 pub fn start_turn_system(
@@ -12,6 +14,8 @@ pub fn start_turn_system(
         commands.insert_resource(Turn {
             current_player: first_player,
             has_drawn_card: false,
+            number: 1,
+            round_caller: None,
         });
         next_state.set(AppState::PlayerTurn);
         info!(target: "mygame","Shift started for player: {:?}", first_player);
@@ -20,13 +24,25 @@ pub fn start_turn_system(
     }
 }
 
-// next turn player
+// call the end of the round, it happens once the cards in the air have landed
 pub fn end_round_system(
-    keyboard: Res<ButtonInput<KeyCode>>,
-    mut next_state: ResMut<NextState<AppState>>,
+    input: ActionInput,
+    turn: Res<Turn>,
+    mut queue: ResMut<ActionQueue>,
 ) {
-    if keyboard.just_pressed(KeyCode::KeyR) {
-            next_state.set(AppState::RoundEnd);
-            info!(target: "mygame", "Round ended by player!");
+    if input.just_pressed(GameAction::EndRound) {
+        if queue.actions.iter().any(|action| matches!(action, QueuedAction::EndRound(_))) {
+            return;
+        }
+        queue.push(QueuedAction::EndRound(turn.current_player));
     }
-}
\ No newline at end of file
+}
+
+// run condition: the current player is sitting at the screen (handoff confirmed)
+pub fn turn_confirmed(
+    hot_seat: Res<HotSeat>,
+    turn: Option<Res<Turn>>,
+) -> bool {
+    let Some(turn) = turn else { return false; };
+    !hot_seat.is_pending(&turn)
+}
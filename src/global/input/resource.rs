@@ -0,0 +1,120 @@
+use bevy::prelude::{KeyCode, GamepadButton};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// game actions that can be bound to keys
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum GameAction {
+    ActivateSpecial,
+    EndRound,
+    NewRound,
+    Confirm,
+    Cancel,
+    NavigateUp,
+    NavigateDown,
+    NavigateLeft,
+    NavigateRight,
+    Pause,
+}
+
+impl GameAction {
+    pub const ALL: [GameAction; 10] = [
+        GameAction::ActivateSpecial,
+        GameAction::EndRound,
+        GameAction::NewRound,
+        GameAction::Confirm,
+        GameAction::Cancel,
+        GameAction::NavigateUp,
+        GameAction::NavigateDown,
+        GameAction::NavigateLeft,
+        GameAction::NavigateRight,
+        GameAction::Pause,
+    ];
+
+    // string table key of the name shown in the controls tab
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            GameAction::ActivateSpecial => "action.activate_special",
+            GameAction::EndRound => "action.end_round",
+            GameAction::NewRound => "action.new_round",
+            GameAction::Confirm => "action.confirm",
+            GameAction::Cancel => "action.cancel",
+            GameAction::NavigateUp => "action.navigate_up",
+            GameAction::NavigateDown => "action.navigate_down",
+            GameAction::NavigateLeft => "action.navigate_left",
+            GameAction::NavigateRight => "action.navigate_right",
+            GameAction::Pause => "action.pause",
+        }
+    }
+
+    pub fn default_keys(&self) -> Vec<KeyCode> {
+        match self {
+            GameAction::ActivateSpecial => vec![KeyCode::KeyE],
+            GameAction::EndRound => vec![KeyCode::KeyR],
+            GameAction::NewRound => vec![KeyCode::KeyN],
+            GameAction::Confirm => vec![KeyCode::Space, KeyCode::Enter],
+            GameAction::Cancel => vec![KeyCode::Backspace],
+            GameAction::NavigateUp => vec![KeyCode::ArrowUp],
+            GameAction::NavigateDown => vec![KeyCode::ArrowDown],
+            GameAction::NavigateLeft => vec![KeyCode::ArrowLeft],
+            GameAction::NavigateRight => vec![KeyCode::ArrowRight],
+            GameAction::Pause => vec![KeyCode::Escape],
+        }
+    }
+
+    // gamepad buttons are fixed, only the keyboard can be rebound
+    pub fn gamepad_buttons(&self) -> Vec<GamepadButton> {
+        match self {
+            GameAction::ActivateSpecial => vec![GamepadButton::West],
+            GameAction::EndRound => vec![GamepadButton::North],
+            GameAction::NewRound => vec![GamepadButton::Start],
+            GameAction::Confirm => vec![GamepadButton::South],
+            GameAction::Cancel => vec![GamepadButton::East],
+            GameAction::NavigateUp => vec![GamepadButton::DPadUp],
+            GameAction::NavigateDown => vec![GamepadButton::DPadDown],
+            GameAction::NavigateLeft => vec![GamepadButton::DPadLeft],
+            GameAction::NavigateRight => vec![GamepadButton::DPadRight],
+            GameAction::Pause => vec![GamepadButton::Select],
+        }
+    }
+}
+
+// keys bound to each action (saved with the settings)
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct KeyBindings(pub HashMap<GameAction, Vec<KeyCode>>);
+
+impl KeyBindings {
+    // bound keys, default keys if the action was never rebound
+    pub fn keys(&self, action: GameAction) -> Vec<KeyCode> {
+        self.0.get(&action).cloned().unwrap_or_else(|| action.default_keys())
+    }
+
+    pub fn bind(&mut self, action: GameAction, key: KeyCode) {
+        self.0.insert(action, vec![key]);
+    }
+
+    pub fn add(&mut self, action: GameAction, key: KeyCode) {
+        let mut keys = self.keys(action);
+        if !keys.contains(&key) {
+            keys.push(key);
+        }
+        self.0.insert(action, keys);
+    }
+
+    // text shown in settings, "E / Space"
+    pub fn keys_text(&self, action: GameAction) -> String {
+        self.keys(action).iter()
+            .map(|key| key_name(*key))
+            .collect::<Vec<_>>()
+            .join(" / ")
+    }
+}
+
+// readable key name ("KeyE" -> "E", "Digit1" -> "1")
+pub fn key_name(key: KeyCode) -> String {
+    let name = format!("{:?}", key);
+    name.strip_prefix("Key")
+        .or_else(|| name.strip_prefix("Digit"))
+        .unwrap_or(&name)
+        .to_string()
+}
@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+use bevy::ecs::system::SystemParam;
+use crate::global::input::resource::GameAction;
+use crate::global::settings::resource::Settings;
+
+// read game actions (keyboard and gamepads) instead of raw keys
+#[derive(SystemParam)]
+pub struct ActionInput<'w, 's> {
+    keyboard: Res<'w, ButtonInput<KeyCode>>,
+    gamepads: Query<'w, 's, &'static Gamepad>,
+    settings: Res<'w, Settings>,
+}
+
+impl ActionInput<'_, '_> {
+    pub fn just_pressed(&self, action: GameAction) -> bool {
+        self.keyboard.any_just_pressed(self.settings.key_bindings.keys(action))
+            || self.gamepads.iter().any(|gamepad| gamepad.any_just_pressed(action.gamepad_buttons()))
+    }
+
+    // direction of the navigation action pressed this frame
+    pub fn navigation(&self) -> Option<IVec2> {
+        if self.just_pressed(GameAction::NavigateUp) {
+            Some(IVec2::Y)
+        } else if self.just_pressed(GameAction::NavigateDown) {
+            Some(IVec2::NEG_Y)
+        } else if self.just_pressed(GameAction::NavigateLeft) {
+            Some(IVec2::NEG_X)
+        } else if self.just_pressed(GameAction::NavigateRight) {
+            Some(IVec2::X)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use bevy::prelude::*;
+use crate::global::settings::system::load_settings;
+
+pub mod resource;
+pub mod system;
+use system::{load_locale, sync_language, update_localized_texts};
+
+pub struct LocalePlugin;
+
+impl Plugin for LocalePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(PreStartup, load_locale.after(load_settings))
+            .add_systems(Update, (sync_language, update_localized_texts).chain());
+    }
+}
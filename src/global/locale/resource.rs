@@ -0,0 +1,93 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Display;
+use crate::game::card::component::Suit;
+
+// folder inside assets with one string table per language (locale/<code>.ron)
+pub const LOCALE_DIR: &str = "locale";
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum Language {
+    #[default]
+    English,
+    Spanish,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::English, Language::Spanish];
+
+    // file name of the string table
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Spanish => "es",
+        }
+    }
+
+    // shown in its own language, so anyone can find theirs
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::Spanish => "Español",
+        }
+    }
+}
+
+// string tables of every language, keys missing in the selected one fall back to english
+#[derive(Resource, Default)]
+pub struct Locale {
+    pub language: Language,
+    pub tables: HashMap<Language, HashMap<String, String>>,
+}
+
+impl Locale {
+    fn lookup(&self, key: &str) -> Option<&String> {
+        self.tables.get(&self.language).and_then(|table| table.get(key))
+            .or_else(|| self.tables.get(&Language::English).and_then(|table| table.get(key)))
+    }
+
+    // text of a key, the key itself if no table has it
+    pub fn get(&self, key: &str) -> String {
+        match self.lookup(key) {
+            Some(text) => text.clone(),
+            None => key.to_string(),
+        }
+    }
+
+    // text of a key with {name} placeholders replaced
+    pub fn format(&self, key: &str, args: &[(&str, &dyn Display)]) -> String {
+        let mut text = self.get(key);
+        for (name, value) in args {
+            text = text.replace(&format!("{{{}}}", name), &value.to_string());
+        }
+        text
+    }
+
+    pub fn suit_name(&self, suit: Suit) -> String {
+        self.get(&format!("suit.{}", suit.key()))
+    }
+
+    // "7 of Cup" / "7 de Copas", figures have their own name
+    pub fn card_name(&self, value: u8, suit: Suit) -> String {
+        if suit == Suit::Joker {
+            return self.get("card.joker");
+        }
+        let value_key = format!("card.value.{}.{}", suit.deck_key(), value);
+        let value_name = self.lookup(&value_key).cloned().unwrap_or_else(|| value.to_string());
+        self.format("card.name", &[("value", &value_name), ("suit", &self.suit_name(suit))])
+    }
+
+    // short value drawn on text faces ("A", "7", "K")
+    pub fn card_short(&self, value: u8, suit: Suit) -> String {
+        let key = match suit {
+            Suit::Joker => "card.short.joker".to_string(),
+            _ => format!("card.short.{}.{}", suit.deck_key(), value),
+        };
+        self.lookup(&key).cloned().unwrap_or_else(|| value.to_string())
+    }
+}
+
+// ui text that follows the selected language
+#[derive(Component)]
+pub struct LocalizedText(pub &'static str);
@@ -0,0 +1,59 @@
+use bevy::prelude::*;
+use std::{collections::HashMap, fs};
+use crate::global::locale::resource::{Language, Locale, LocalizedText, LOCALE_DIR};
+use crate::global::assets_dir;
+use crate::global::settings::resource::Settings;
+
+// read a string table, empty if missing or broken
+fn load_table(language: Language) -> HashMap<String, String> {
+    let path = assets_dir().join(LOCALE_DIR).join(format!("{}.ron", language.code()));
+
+    let Ok(content) = fs::read_to_string(&path) else {
+        warn!(target: "mygame", "No string table at {:?}", path);
+        return HashMap::new();
+    };
+
+    match ron::from_str(&content) {
+        Ok(table) => table,
+        Err(err) => {
+            warn!(target: "mygame", "Could not parse {:?}: {}", path, err);
+            HashMap::new()
+        }
+    }
+}
+
+// load every language at startup, switching is instant later
+pub fn load_locale(mut commands: Commands, settings: Res<Settings>) {
+    let tables = Language::ALL.iter()
+        .map(|language| (*language, load_table(*language)))
+        .collect();
+
+    commands.insert_resource(Locale {
+        language: settings.language,
+        tables,
+    });
+}
+
+pub fn sync_language(settings: Res<Settings>, mut locale: ResMut<Locale>) {
+    if settings.is_changed() && locale.language != settings.language {
+        locale.language = settings.language;
+        info!(target: "mygame", "Language: {:?}", locale.language);
+    }
+}
+
+// translate labels already on screen when the language changes
+pub fn update_localized_texts(
+    locale: Res<Locale>,
+    mut text_query: Query<(&mut Text, &LocalizedText)>,
+) {
+    if !locale.is_changed() {
+        return;
+    }
+
+    for (mut text, localized) in text_query.iter_mut() {
+        let translated = locale.get(localized.0);
+        if text.0 != translated {
+            text.0 = translated;
+        }
+    }
+}
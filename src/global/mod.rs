@@ -1,6 +1,19 @@
 use bevy::prelude::*;
+use bevy::asset::io::file::FileAssetReader;
+use std::path::PathBuf;
+
+pub mod storage;
+pub mod settings;
+pub mod input;
+pub mod locale;
+
+// folder the asset server reads from (BEVY_ASSET_ROOT, the cargo manifest or the executable
+// folder, never the working directory), for the files read without it
+pub fn assets_dir() -> PathBuf {
+    FileAssetReader::get_base_path().join("assets")
+}
 
 // create camera
 pub fn setup_camera(mut commands: Commands) {
-    commands.spawn(Camera2d::default());
-}
\ No newline at end of file
+    commands.spawn(Camera2d);
+}
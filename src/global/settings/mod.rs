@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+
+pub mod resource;
+pub mod system;
+use system::{load_settings, apply_settings};
+
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(PreStartup, load_settings)
+            .add_systems(Update, apply_settings);
+    }
+}
@@ -0,0 +1,317 @@
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+use crate::global::input::resource::KeyBindings;
+use crate::global::locale::resource::{Language, Locale};
+use crate::game::deck_theme::resource::BUILTIN_THEME;
+use crate::game::deck::resource::DeckType;
+
+// file inside the save folder
+pub const SETTINGS_FILE: &str = "settings.ron";
+
+// selectable window resolutions
+pub const RESOLUTIONS: [(u32, u32); 4] = [(1280, 720), (1600, 900), (1920, 1080), (2560, 1440)];
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+pub enum WindowModeSetting {
+    Windowed,
+    Borderless,
+    Fullscreen,
+}
+
+// player configuration, saved in saves/settings.ron
+#[derive(Resource, Serialize, Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct Settings {
+    pub master_volume: f32, // 0.0 - 1.0, scales music and effects
+    pub music_volume: f32, // 0.0 - 1.0, scales each track level
+    pub effects_volume: f32, // 0.0 - 1.0
+    pub mute_master: bool,
+    pub mute_music: bool,
+    pub mute_effects: bool,
+    pub mute_unfocused: bool, // silence the game while the window is in the background
+    pub double_click_time: f32, // seconds
+    pub animation_speed: f32, // multiplier, 1.0 = normal
+    pub reduced_motion: bool, // faster card animations, no fades or decorative ones (shuffles, particles)
+    pub large_cards: bool,
+    pub high_contrast: bool, // value and suit written over the art
+    pub colorblind_suits: bool, // shape and color marker of the suit on each card
+    pub window_mode: WindowModeSetting,
+    pub resolution: (u32, u32),
+    pub hot_seat: bool, // opt-in, off for single-screen play
+    pub discard_history: bool, // the discard pile can be browsed, see ui/discard_history
+    pub drag_and_drop: bool, // drag the drawn card instead of click/double click
+    pub language: Language,
+    pub deck_theme: String, // folder of the theme, see game/deck_theme
+    pub deck_type: DeckType, // used from the next match
+    pub excluded_values: Vec<u8>, // values removed from the deck, see DeckType::compositions
+    pub jokers: bool, // two extra jokers
+    pub joker_value: i32, // points of a joker, 0 or negative helps the one holding it
+    pub key_bindings: KeyBindings,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            master_volume: 1.0,
+            music_volume: 1.0,
+            effects_volume: 1.0,
+            mute_master: false,
+            mute_music: false,
+            mute_effects: false,
+            mute_unfocused: true,
+            double_click_time: 0.4,
+            animation_speed: 1.0,
+            reduced_motion: false,
+            large_cards: false,
+            high_contrast: false,
+            colorblind_suits: false,
+            window_mode: WindowModeSetting::Windowed,
+            resolution: (1280, 720),
+            hot_seat: false,
+            discard_history: true,
+            drag_and_drop: false,
+            language: Language::English,
+            deck_theme: BUILTIN_THEME.to_string(),
+            deck_type: DeckType::Spanish,
+            excluded_values: Vec::new(),
+            jokers: false,
+            joker_value: 0,
+            key_bindings: KeyBindings::default(),
+        }
+    }
+}
+
+// each row of the settings screen
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SettingKey {
+    Language,
+    DeckTheme,
+    DeckType,
+    DeckValues,
+    Jokers,
+    JokerValue,
+    MasterVolume,
+    MusicVolume,
+    EffectsVolume,
+    MuteMaster,
+    MuteMusic,
+    MuteEffects,
+    MuteUnfocused,
+    DoubleClick,
+    AnimationSpeed,
+    ReducedMotion,
+    LargeCards,
+    HighContrast,
+    ColorblindSuits,
+    WindowMode,
+    Resolution,
+    HotSeat,
+    DiscardHistory,
+    DragAndDrop,
+}
+
+impl SettingKey {
+    // rows of the general tab
+    pub const GENERAL: [SettingKey; 8] = [
+        SettingKey::Language,
+        SettingKey::DoubleClick,
+        SettingKey::AnimationSpeed,
+        SettingKey::WindowMode,
+        SettingKey::Resolution,
+        SettingKey::HotSeat,
+        SettingKey::DiscardHistory,
+        SettingKey::DragAndDrop,
+    ];
+
+    // rows of the deck tab, used from the next match (except the theme)
+    pub const DECK: [SettingKey; 5] = [
+        SettingKey::DeckType,
+        SettingKey::DeckValues,
+        SettingKey::Jokers,
+        SettingKey::JokerValue,
+        SettingKey::DeckTheme,
+    ];
+
+    // rows of the accessibility tab
+    pub const ACCESSIBILITY: [SettingKey; 4] = [
+        SettingKey::LargeCards,
+        SettingKey::HighContrast,
+        SettingKey::ColorblindSuits,
+        SettingKey::ReducedMotion,
+    ];
+
+    // rows of the audio tab
+    pub const AUDIO: [SettingKey; 7] = [
+        SettingKey::MasterVolume,
+        SettingKey::MusicVolume,
+        SettingKey::EffectsVolume,
+        SettingKey::MuteMaster,
+        SettingKey::MuteMusic,
+        SettingKey::MuteEffects,
+        SettingKey::MuteUnfocused,
+    ];
+
+    // string table key of the row label
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            SettingKey::Language => "setting.language",
+            SettingKey::DeckTheme => "setting.deck_theme",
+            SettingKey::DeckType => "setting.deck_type",
+            SettingKey::DeckValues => "setting.deck_values",
+            SettingKey::Jokers => "setting.jokers",
+            SettingKey::JokerValue => "setting.joker_value",
+            SettingKey::MasterVolume => "setting.master_volume",
+            SettingKey::MusicVolume => "setting.music_volume",
+            SettingKey::EffectsVolume => "setting.effects_volume",
+            SettingKey::MuteMaster => "setting.mute_master",
+            SettingKey::MuteMusic => "setting.mute_music",
+            SettingKey::MuteEffects => "setting.mute_effects",
+            SettingKey::MuteUnfocused => "setting.mute_unfocused",
+            SettingKey::DoubleClick => "setting.double_click",
+            SettingKey::AnimationSpeed => "setting.animation_speed",
+            SettingKey::ReducedMotion => "setting.reduced_motion",
+            SettingKey::LargeCards => "setting.large_cards",
+            SettingKey::HighContrast => "setting.high_contrast",
+            SettingKey::ColorblindSuits => "setting.colorblind_suits",
+            SettingKey::WindowMode => "setting.window_mode",
+            SettingKey::Resolution => "setting.resolution",
+            SettingKey::HotSeat => "setting.hot_seat",
+            SettingKey::DiscardHistory => "setting.discard_history",
+            SettingKey::DragAndDrop => "setting.drag_and_drop",
+        }
+    }
+}
+
+fn on_off(value: bool, locale: &Locale) -> String {
+    if value { locale.get("setting.on") } else { locale.get("setting.off") }
+}
+
+impl Settings {
+    // value shown in the settings screen
+    pub fn value_text(&self, key: SettingKey, locale: &Locale) -> String {
+        match key {
+            SettingKey::Language => self.language.native_name().to_string(),
+            SettingKey::DeckTheme => self.deck_theme.clone(), // the settings screen shows the theme name
+            SettingKey::DeckType => locale.get(match self.deck_type {
+                DeckType::Spanish => "deck.spanish",
+                DeckType::French => "deck.french",
+            }),
+            SettingKey::DeckValues => {
+                let count = self.deck_size().to_string();
+                if self.excluded_values.is_empty() {
+                    locale.format("deck.card_count", &[("count", &count)])
+                } else {
+                    let values = self.excluded_values.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(", ");
+                    locale.format("deck.card_count_without", &[("count", &count), ("values", &values)])
+                }
+            }
+            SettingKey::Jokers => on_off(self.jokers, locale),
+            SettingKey::JokerValue => format!("{:+}", self.joker_value),
+            SettingKey::MasterVolume => format!("{}%", (self.master_volume * 100.0).round()),
+            SettingKey::MusicVolume => format!("{}%", (self.music_volume * 100.0).round()),
+            SettingKey::EffectsVolume => format!("{}%", (self.effects_volume * 100.0).round()),
+            SettingKey::MuteMaster => on_off(self.mute_master, locale),
+            SettingKey::MuteMusic => on_off(self.mute_music, locale),
+            SettingKey::MuteEffects => on_off(self.mute_effects, locale),
+            SettingKey::MuteUnfocused => on_off(self.mute_unfocused, locale),
+            SettingKey::DoubleClick => format!("{:.2}s", self.double_click_time),
+            SettingKey::AnimationSpeed => format!("x{:.2}", self.animation_speed),
+            SettingKey::ReducedMotion => on_off(self.reduced_motion, locale),
+            SettingKey::LargeCards => on_off(self.large_cards, locale),
+            SettingKey::HighContrast => on_off(self.high_contrast, locale),
+            SettingKey::ColorblindSuits => on_off(self.colorblind_suits, locale),
+            SettingKey::WindowMode => locale.get(match self.window_mode {
+                WindowModeSetting::Windowed => "window_mode.windowed",
+                WindowModeSetting::Borderless => "window_mode.borderless",
+                WindowModeSetting::Fullscreen => "window_mode.fullscreen",
+            }),
+            SettingKey::Resolution => format!("{}x{}", self.resolution.0, self.resolution.1),
+            SettingKey::HotSeat => on_off(self.hot_seat, locale),
+            SettingKey::DiscardHistory => on_off(self.discard_history, locale),
+            SettingKey::DragAndDrop => on_off(self.drag_and_drop, locale),
+        }
+    }
+
+    // change a value one step (direction = -1 or 1)
+    pub fn step(&mut self, key: SettingKey, direction: i32) {
+        let dir = direction as f32;
+        match key {
+            SettingKey::Language => {
+                let index = Language::ALL.iter().position(|l| *l == self.language).unwrap_or(0);
+                self.language = Language::ALL[cycle(index, Language::ALL.len(), direction)];
+            }
+            // the list of themes is discovered at startup, the settings screen cycles it
+            SettingKey::DeckTheme => {}
+            SettingKey::DeckType => {
+                let index = DeckType::ALL.iter().position(|d| *d == self.deck_type).unwrap_or(0);
+                self.deck_type = DeckType::ALL[cycle(index, DeckType::ALL.len(), direction)];
+                // compositions are different for each deck
+                self.excluded_values.clear();
+            }
+            SettingKey::DeckValues => {
+                let compositions = self.deck_type.compositions();
+                let index = compositions.iter().position(|c| *c == self.excluded_values.as_slice()).unwrap_or(0);
+                self.excluded_values = compositions[cycle(index, compositions.len(), direction)].to_vec();
+            }
+            SettingKey::Jokers => self.jokers = !self.jokers,
+            SettingKey::JokerValue => self.joker_value = (self.joker_value + direction).clamp(-5, 10),
+            SettingKey::MasterVolume => {
+                self.master_volume = (self.master_volume + dir * 0.1).clamp(0.0, 1.0);
+            }
+            SettingKey::MusicVolume => {
+                self.music_volume = (self.music_volume + dir * 0.1).clamp(0.0, 1.0);
+            }
+            SettingKey::EffectsVolume => {
+                self.effects_volume = (self.effects_volume + dir * 0.1).clamp(0.0, 1.0);
+            }
+            SettingKey::MuteMaster => self.mute_master = !self.mute_master,
+            SettingKey::MuteMusic => self.mute_music = !self.mute_music,
+            SettingKey::MuteEffects => self.mute_effects = !self.mute_effects,
+            SettingKey::MuteUnfocused => self.mute_unfocused = !self.mute_unfocused,
+            SettingKey::DoubleClick => {
+                self.double_click_time = (self.double_click_time + dir * 0.05).clamp(0.2, 1.0);
+            }
+            SettingKey::AnimationSpeed => {
+                self.animation_speed = (self.animation_speed + dir * 0.25).clamp(0.5, 3.0);
+            }
+            SettingKey::ReducedMotion => self.reduced_motion = !self.reduced_motion,
+            SettingKey::LargeCards => self.large_cards = !self.large_cards,
+            SettingKey::HighContrast => self.high_contrast = !self.high_contrast,
+            SettingKey::ColorblindSuits => self.colorblind_suits = !self.colorblind_suits,
+            SettingKey::WindowMode => {
+                let modes = [WindowModeSetting::Windowed, WindowModeSetting::Borderless, WindowModeSetting::Fullscreen];
+                let index = modes.iter().position(|m| *m == self.window_mode).unwrap_or(0);
+                self.window_mode = modes[cycle(index, modes.len(), direction)];
+            }
+            SettingKey::Resolution => {
+                let index = RESOLUTIONS.iter().position(|r| *r == self.resolution).unwrap_or(0);
+                self.resolution = RESOLUTIONS[cycle(index, RESOLUTIONS.len(), direction)];
+            }
+            SettingKey::HotSeat => self.hot_seat = !self.hot_seat,
+            SettingKey::DiscardHistory => self.discard_history = !self.discard_history,
+            SettingKey::DragAndDrop => self.drag_and_drop = !self.drag_and_drop,
+        }
+    }
+}
+
+impl Settings {
+    // cards of the deck chosen for the next match
+    pub fn deck_size(&self) -> usize {
+        let values = self.deck_type.values().filter(|v| !self.excluded_values.contains(v)).count();
+        values * self.deck_type.suits().len() + if self.jokers { 2 } else { 0 }
+    }
+
+    // final level of each bus: master * bus volume, 0 when muted
+    pub fn music_gain(&self) -> f32 {
+        if self.mute_master || self.mute_music { 0.0 } else { self.master_volume * self.music_volume }
+    }
+
+    pub fn effects_gain(&self) -> f32 {
+        if self.mute_master || self.mute_effects { 0.0 } else { self.master_volume * self.effects_volume }
+    }
+}
+
+// next index of a list, wrapping around
+fn cycle(index: usize, len: usize, direction: i32) -> usize {
+    (index as i32 + direction).rem_euclid(len as i32) as usize
+}
@@ -0,0 +1,54 @@
+use bevy::prelude::*;
+use bevy::window::{PrimaryWindow, WindowMode, MonitorSelection, VideoModeSelection};
+use crate::global::storage;
+use crate::global::settings::resource::{Settings, SETTINGS_FILE, WindowModeSetting};
+use crate::game::{card::component::DoubleClick, turn_player::component::HotSeat};
+
+// load settings from disk before anything else
+pub fn load_settings(mut commands: Commands) {
+    let settings: Settings = storage::load_or_default(SETTINGS_FILE);
+    commands.insert_resource(settings);
+}
+
+pub fn save_settings(settings: &Settings) {
+    storage::save(SETTINGS_FILE, settings);
+}
+
+// apply settings at startup and every time they change
+pub fn apply_settings(
+    settings: Res<Settings>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+    mut double_click: ResMut<DoubleClick>,
+    mut hot_seat: ResMut<HotSeat>,
+    mut applied_window: Local<Option<(WindowModeSetting, (u32, u32))>>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+
+    double_click.time_limit = settings.double_click_time;
+    hot_seat.enabled = settings.hot_seat;
+
+    // the window is resizable: only touch it when the window settings themselves change,
+    // not when another setting does (that would undo a manual resize)
+    let window_settings = (settings.window_mode, settings.resolution);
+    if *applied_window == Some(window_settings) {
+        return;
+    }
+    let Ok(mut window) = windows.single_mut() else { return; };
+    *applied_window = Some(window_settings);
+
+    let mode = match settings.window_mode {
+        WindowModeSetting::Windowed => WindowMode::Windowed,
+        WindowModeSetting::Borderless => WindowMode::BorderlessFullscreen(MonitorSelection::Current),
+        WindowModeSetting::Fullscreen => WindowMode::Fullscreen(MonitorSelection::Current, VideoModeSelection::Current),
+    };
+    if window.mode != mode {
+        window.mode = mode;
+    }
+
+    let (width, height) = settings.resolution;
+    if window.resolution.width() != width as f32 || window.resolution.height() != height as f32 {
+        window.resolution.set(width as f32, height as f32);
+    }
+}
@@ -0,0 +1,56 @@
+use bevy::prelude::*;
+use serde::{Serialize, de::DeserializeOwned};
+use std::{fs, path::PathBuf};
+
+// folder (next to the working directory) where profiles and config are saved
+const SAVE_DIR: &str = "saves";
+
+fn save_path(file_name: &str) -> PathBuf {
+    PathBuf::from(SAVE_DIR).join(file_name)
+}
+
+// read a ron file from the save folder, default value if missing or broken;
+// a broken file is kept as <file>.bak so the next save does not destroy it
+pub fn load_or_default<T: DeserializeOwned + Default>(file_name: &str) -> T {
+    let path = save_path(file_name);
+
+    let Ok(content) = fs::read_to_string(&path) else {
+        info!(target: "mygame", "No save file at {:?}, using defaults", path);
+        return T::default();
+    };
+
+    match ron::from_str(&content) {
+        Ok(value) => value,
+        Err(err) => {
+            warn!(target: "mygame", "Could not parse {:?}: {}", path, err);
+            let backup = path.with_extension("ron.bak");
+            match fs::rename(&path, &backup) {
+                Ok(()) => warn!(target: "mygame", "Broken save moved to {:?}, using defaults", backup),
+                Err(err) => warn!(target: "mygame", "Could not move {:?} to {:?}: {}", path, backup, err),
+            }
+            T::default()
+        }
+    }
+}
+
+// write a value as ron in the save folder
+pub fn save<T: Serialize>(file_name: &str, value: &T) {
+    let path = save_path(file_name);
+
+    if let Err(err) = fs::create_dir_all(SAVE_DIR) {
+        warn!(target: "mygame", "Could not create save folder: {}", err);
+        return;
+    }
+
+    let content = match ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default()) {
+        Ok(c) => c,
+        Err(err) => {
+            warn!(target: "mygame", "Could not serialize {:?}: {}", path, err);
+            return;
+        }
+    };
+
+    if let Err(err) = fs::write(&path, content) {
+        warn!(target: "mygame", "Could not write {:?}: {}", path, err);
+    }
+}
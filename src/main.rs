@@ -7,6 +7,8 @@ mod ui;
 use game::GamePlugin;
 use ui::UiPlugin;
 use global::setup_camera;
+use global::settings::SettingsPlugin;
+use global::locale::LocalePlugin;
 
 fn main() {
     App::new()
@@ -14,12 +16,13 @@ fn main() {
         primary_window: Some(Window {
             resolution: (1280, 720).into(),
             title: "Game test".to_string(),
-            resizable: false,
             ..default()
         }),
         ..default()
     }))
     .add_systems(Startup, setup_camera)
+    .add_plugins(SettingsPlugin)
+    .add_plugins(LocalePlugin)
     .add_plugins(GamePlugin)
     .add_plugins(UiPlugin)
     .run();
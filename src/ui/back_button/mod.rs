@@ -1,5 +1,5 @@
 use bevy::prelude::*;
-use crate::game::gamestate::AppState;
+use crate::game::gamestate::{AppState, PauseState};
 use crate::ui::back_button::system::{spawn_button, clean_button, button_hover, button_visuals, handle_button};
 
 pub mod component;
@@ -18,7 +18,7 @@ impl Plugin for BackButtonPlugin {
             .add_systems(OnExit(AppState::PlayerTurn), clean_button)
             .add_systems(OnExit(AppState::RoundEnd), clean_button)
             
-            .add_systems(Update, (button_hover, button_visuals, handle_button).run_if(is_in_game));
+            .add_systems(Update, (button_hover, button_visuals, handle_button).run_if(is_in_game).run_if(in_state(PauseState::Running)));
     }
 }
 
@@ -1,16 +1,16 @@
 use bevy::prelude::*;
 use crate::ui::back_button::component::{ButtonState, BackButton, SetupUI, ButtonImages};
-use crate::game::gamestate::AppState;
+use crate::game::{gamestate::PauseState, table::resource::TableLayout};
+use crate::ui::pause_menu::component::{PausePage, PauseScreen};
 use bevy::window::PrimaryWindow;
+use crate::ui::focus::component::{Focusable, FocusActivated};
 
 pub fn spawn_button(
     mut commands: Commands, 
     asset_server: Res<AssetServer>,
     existing_buttons: Query<Entity, With<BackButton>>,
-    windows: Query<&Window, With<PrimaryWindow>>,
+    layout: Res<TableLayout>,
 ) {
-    let Ok(window) = windows.single() else { return; };
-
     // spawn if not exists now
     if !existing_buttons.is_empty() {
         return;
@@ -23,10 +23,11 @@ pub fn spawn_button(
     // Spawn with default scale
     commands.spawn((
         Sprite::from_image(image_back_normal.clone()),
-        Transform::from_xyz(window.width() * -0.44, window.height() * 0.4, 10.0).with_scale(Vec3::splat(0.7)),
+        Transform::from_translation(layout.back_button_position()).with_scale(Vec3::splat(0.7 * layout.zoom())),
         ButtonImages {normal: image_back_normal, pressed: image_back_pressed, hovered: image_back_hover},
         ButtonState::Normal,
         BackButton,
+        Focusable,
         SetupUI,
     ));
 }
@@ -44,6 +45,7 @@ pub fn clean_button(
 
 
 // Handle and detect button
+#[allow(clippy::too_many_arguments)]
 pub fn handle_button(
     windows: Query<&Window, With<PrimaryWindow>>,
     mouse_input: Res<ButtonInput<MouseButton>>,
@@ -51,8 +53,17 @@ pub fn handle_button(
     images: Res<Assets<Image>>,
     sprites: Query<&Sprite>,
     mut button_query: Query<(&Transform, &mut ButtonState, Option<&BackButton>, Entity)>,
-    mut next_state: ResMut<NextState<AppState>>,
+    mut next_pause: ResMut<NextState<PauseState>>,
+    mut pause_screen: ResMut<PauseScreen>,
+    mut activated: MessageReader<FocusActivated>,
 ) {
+    // keyboard/gamepad confirm on the back button
+    for FocusActivated(entity) in activated.read() {
+        if let Ok((_, _, Some(_back), _)) = button_query.get(*entity) {
+            open_quit_confirmation(&mut next_pause, &mut pause_screen);
+        }
+    }
+
     // only if click left mouse button
     if mouse_input.just_pressed(MouseButton::Left) {
         // obtain window, camera, cursor/world position
@@ -92,9 +103,8 @@ pub fn handle_button(
                 // if the cursor remains over the button: execute action
                 if detect_button(world_pos, transform, image) {
                     if back.is_some() {
-                        next_state.set(AppState::MainMenu);
-                        info!(target: "mygame", "Return to menu...");
-                    } 
+                        open_quit_confirmation(&mut next_pause, &mut pause_screen);
+                    }
                     *state = ButtonState::Normal;
                 } else {
                     // else, change to normal/hovered state
@@ -105,6 +115,16 @@ pub fn handle_button(
     }
 }
 
+// the back button asks before leaving: pause menu opened on the quit confirmation
+fn open_quit_confirmation(
+    next_pause: &mut NextState<PauseState>,
+    pause_screen: &mut PauseScreen,
+) {
+    pause_screen.page = PausePage::ConfirmQuit;
+    next_pause.set(PauseState::Paused);
+    info!(target: "mygame", "Confirm return to menu...");
+}
+
 // auxiliar function - verify if click is inside the button
 fn detect_button(
     cursor_pos: Vec2,
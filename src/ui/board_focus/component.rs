@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+
+// places of the table reachable with keyboard/gamepad
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum BoardTarget {
+    BackButton,
+    Deck,
+    Graveyard,
+    DrawnCard,
+    HandSlot(Entity, usize), // player, card index in hand
+}
+
+#[derive(Resource, Default)]
+pub struct BoardFocus {
+    pub target: Option<BoardTarget>,
+}
+
+// outline drawn behind the focused card
+#[derive(Component)]
+pub struct BoardFocusHighlight;
@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+use bevy::ui::UiSystems;
+use crate::game::gamestate::{AppState, PauseState};
+use crate::game::card::system::card_visual;
+use crate::ui::board_focus::component::BoardFocus;
+use crate::ui::focus::system::{navigate_focus, activate_focus};
+use crate::ui::board_focus::system::{navigate_board, highlight_board, reset_board_focus};
+
+pub mod component;
+pub mod system;
+
+pub struct BoardFocusPlugin;
+
+impl Plugin for BoardFocusPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<BoardFocus>()
+            .add_systems(OnEnter(AppState::Setup), reset_board_focus)
+            .add_systems(PreUpdate, navigate_board.after(UiSystems::Focus).after(navigate_focus).before(activate_focus).run_if(in_state(AppState::PlayerTurn)).run_if(in_state(PauseState::Running)))
+            .add_systems(Update, highlight_board.after(card_visual));
+    }
+}
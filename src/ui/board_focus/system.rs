@@ -0,0 +1,174 @@
+use bevy::prelude::*;
+use bevy::ecs::system::SystemParam;
+use bevy::picking::hover::HoverMap;
+use crate::game::{card::component::{Card, CardPosition, CardDrag, CARD_SIZE}, deck::component::Deck, graveyard::component::Graveyard, hand::component::Hand, player::component::Player};
+use crate::game::gamestate::{AppState, GameEntity};
+use crate::global::input::system::ActionInput;
+use crate::ui::back_button::component::BackButton;
+use crate::ui::board_focus::component::{BoardFocus, BoardTarget, BoardFocusHighlight};
+use crate::ui::focus::component::{FocusState, FOCUS_COLOR};
+
+// mouse, keyboard and gamepad input on the table, used by card_selection
+#[derive(SystemParam)]
+pub struct BoardInput<'w, 's> {
+    pub mouse: Res<'w, ButtonInput<MouseButton>>,
+    pub hover_map: Res<'w, HoverMap>,
+    pub input: ActionInput<'w, 's>,
+    pub board_focus: Res<'w, BoardFocus>,
+    pub focus: Res<'w, FocusState>,
+    pub drag: Res<'w, CardDrag>,
+}
+
+pub fn reset_board_focus(mut board_focus: ResMut<BoardFocus>) {
+    board_focus.target = None;
+}
+
+// rows of the table from top to bottom
+fn board_rows(
+    player_query: &Query<(Entity, &Player)>,
+    hand_query: &Query<&Hand>,
+    has_drawn_card: bool,
+) -> Vec<Vec<BoardTarget>> {
+    let hand_row = |local: bool| -> Vec<BoardTarget> {
+        player_query.iter()
+            .filter(|(_, player)| player.is_local_player == local)
+            .flat_map(|(entity, player)| {
+                let count = hand_query.get(player.hand).map(|h| h.cards.len()).unwrap_or(0);
+                (0..count).map(move |slot| BoardTarget::HandSlot(entity, slot))
+            })
+            .collect()
+    };
+
+    let mut middle = vec![BoardTarget::Graveyard];
+    if has_drawn_card {
+        middle.push(BoardTarget::DrawnCard);
+    }
+    middle.push(BoardTarget::Deck);
+
+    vec![vec![BoardTarget::BackButton], hand_row(false), middle, hand_row(true)]
+        .into_iter()
+        .filter(|row| !row.is_empty())
+        .collect()
+}
+
+// move the board focus with arrows/d-pad
+pub fn navigate_board(
+    input: ActionInput,
+    mut board_focus: ResMut<BoardFocus>,
+    mut focus: ResMut<FocusState>,
+    player_query: Query<(Entity, &Player)>,
+    hand_query: Query<&Hand>,
+    card_query: Query<&Card>,
+    back_query: Query<Entity, With<BackButton>>,
+) {
+    // an overlay owns the navigation
+    if focus.overlay_active {
+        return;
+    }
+
+    let has_drawn_card = card_query.iter().any(|card| matches!(card.position, CardPosition::DrawnCard(_)));
+    let rows = board_rows(&player_query, &hand_query, has_drawn_card);
+
+    // position of the current target in the grid (it can disappear, e.g. drawn card discarded)
+    let current = board_focus.target.and_then(|target| {
+        rows.iter().enumerate().find_map(|(r, row)| row.iter().position(|t| *t == target).map(|c| (r, c)))
+    });
+    if current.is_none() && board_focus.target.is_some() {
+        board_focus.target = Some(BoardTarget::Deck);
+    }
+
+    if let Some(direction) = input.navigation() {
+        match current {
+            None => board_focus.target = Some(BoardTarget::Deck),
+            Some((row, col)) => {
+                let (row, col) = if direction.y != 0 {
+                    // rows are listed top to bottom
+                    let new_row = (row as i32 - direction.y).clamp(0, rows.len() as i32 - 1) as usize;
+                    // keep the relative column
+                    let old_len = rows[row].len().max(2) - 1;
+                    let new_len = rows[new_row].len() - 1;
+                    (new_row, ((col * new_len) as f32 / old_len as f32).round() as usize)
+                } else {
+                    let new_col = (col as i32 + direction.x).clamp(0, rows[row].len() as i32 - 1) as usize;
+                    (row, new_col)
+                };
+                board_focus.target = Some(rows[row][col.min(rows[row].len() - 1)]);
+            }
+        }
+    }
+
+    // the back button is highlighted by the generic focus
+    focus.entity = match board_focus.target {
+        Some(BoardTarget::BackButton) => back_query.single().ok(),
+        _ => None,
+    };
+}
+
+// card entity (or table position) of a board target
+pub fn board_target_card(
+    target: BoardTarget,
+    player_query: &Query<(Entity, &Player)>,
+    hand_query: &Query<&Hand>,
+    deck_query: &Query<&Deck>,
+    graveyard_query: &Query<&Graveyard>,
+    card_query: &Query<(Entity, &Card)>,
+) -> Option<Entity> {
+    match target {
+        BoardTarget::HandSlot(player_entity, slot) => {
+            let (_, player) = player_query.get(player_entity).ok()?;
+            hand_query.get(player.hand).ok()?.cards.get(slot).copied()
+        }
+        BoardTarget::DrawnCard => card_query.iter()
+            .find(|(_, card)| matches!(card.position, CardPosition::DrawnCard(_)))
+            .map(|(entity, _)| entity),
+        BoardTarget::Deck => deck_query.single().ok()?.cards_values.first().copied(),
+        BoardTarget::Graveyard => graveyard_query.single().ok()?.cards.last().copied(),
+        BoardTarget::BackButton => None,
+    }
+}
+
+// outline behind the focused card
+#[allow(clippy::too_many_arguments)]
+pub fn highlight_board(
+    mut commands: Commands,
+    board_focus: Res<BoardFocus>,
+    state: Res<State<AppState>>,
+    focus: Res<FocusState>,
+    player_query: Query<(Entity, &Player)>,
+    hand_query: Query<&Hand>,
+    deck_query: Query<&Deck>,
+    graveyard_query: Query<&Graveyard>,
+    card_query: Query<(Entity, &Card)>,
+    transform_query: Query<&Transform, Without<BoardFocusHighlight>>,
+    mut highlight_query: Query<(Entity, &mut Transform, &mut Visibility), With<BoardFocusHighlight>>,
+) {
+    let card_entity = board_focus.target
+        .filter(|_| *state.get() == AppState::PlayerTurn && !focus.overlay_active)
+        .and_then(|target| board_target_card(target, &player_query, &hand_query, &deck_query, &graveyard_query, &card_query));
+    let card_transform = card_entity.and_then(|e| transform_query.get(e).ok());
+
+    let Ok((_, mut transform, mut visibility)) = highlight_query.single_mut() else {
+        // spawn once per game
+        if card_transform.is_some() {
+            commands.spawn((
+                Sprite::from_color(FOCUS_COLOR, CARD_SIZE + Vec2::splat(10.0)),
+                Transform::default(),
+                Visibility::Hidden,
+                Pickable::IGNORE, // larger than the card, must not take its clicks
+                BoardFocusHighlight,
+                GameEntity,
+            ));
+        }
+        return;
+    };
+
+    match card_transform {
+        Some(card_transform) => {
+            transform.translation = card_transform.translation - Vec3::Z * 0.5;
+            transform.rotation = card_transform.rotation;
+            transform.scale = card_transform.scale;
+            *visibility = Visibility::Visible;
+        }
+        None => *visibility = Visibility::Hidden,
+    }
+}
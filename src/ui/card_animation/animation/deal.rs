@@ -1,64 +1,20 @@
 use bevy::prelude::*;
 use crate::game::card::component::Card;
-use crate::ui::card_animation::component::{CardAnimation, AnimationType, AnimationState};
+use crate::ui::card_animation::component::flip_animation;
+use crate::ui::tween::component::{TweenCompleted, TweenKind};
 
-pub fn animate_deal(
+// the card arrived to the hand: flip it when it is one of the peeked cards
+pub fn finish_deal(
     mut commands: Commands,
-    mut card_query: Query<(Entity, &mut Transform, &mut Sprite, &mut CardAnimation, &mut Card)>,
-    time: Res<Time>,
+    mut completed: MessageReader<TweenCompleted>,
+    mut card_query: Query<(&mut Card, &Transform)>,
 ) {
-    for (entity, mut transform, mut sprite, mut animation, mut card) in card_query.iter_mut() {
-        
-        if animation.animation_type == AnimationType::Deal {
-            
-            // wait delay
-            if animation.delay_elapsed < animation.delay {
-                transform.translation.z = -10.0; // keep Z down
-                sprite.color = Color::srgba(1.0, 1.0, 1.0, 0.0);
-                animation.delay_elapsed += time.delta_secs();
-                continue;
-            }
-            
-            if animation.progress == 0.0 {
-                transform.translation.z = 100.0;  // visible during the animation
-                sprite.color = Color::WHITE;
-            }
+    for event in completed.read().filter(|event| event.kind == TweenKind::Deal) {
+        let Ok((mut card, transform)) = card_query.get_mut(event.entity) else { continue; };
+        card.is_being_dealt = false;
 
-            // animate
-            if let Some(target) = animation.target_position {
-                // Interpolate con easing
-                let t = animation.progress * animation.progress * (3.0 - 2.0 * animation.progress);
-                transform.translation = animation.original_position.lerp(target, t);
-                
-                // Update progress
-                animation.progress += time.delta_secs() / animation.duration;
-                
-                // when animation finish
-                if animation.progress >= 1.0 {
-                    card.is_being_dealt = false;
-                    transform.translation = target;  // final position
-                    transform.translation.z = 10.0;
-                    
-                    // to do flip
-                    if card.face_up {
-                        commands.entity(entity).insert(CardAnimation {
-                            animation_type: AnimationType::Flip,
-                            progress: 0.0,
-                            duration: 0.4,
-                            state: AnimationState::Animating,
-                            original_scale: transform.scale,
-                            original_position: transform.translation,
-                            original_rotation: transform.rotation,
-                            target_position: None,
-                            delay: 0.0,
-                            delay_elapsed: 0.0,
-                        });
-                    } else {
-                        // if not flip, remove animation
-                        commands.entity(entity).remove::<CardAnimation>();
-                    }
-                }
-            }
+        if card.face_up {
+            commands.entity(event.entity).insert(flip_animation(transform));
         }
     }
-}
\ No newline at end of file
+}
@@ -1,8 +1,9 @@
 use bevy::prelude::*;
 use crate::game::card::component::{Card, CardPosition, PreviousCardPosition, PreviousFaceUp};
-use crate::ui::card_animation::component::{CardAnimation, AnimationState, AnimationType};
+use crate::ui::card_animation::component::{CardAnimation, flip_animation};
 
 // detect when face_up change
+#[allow(clippy::type_complexity)]
 pub fn detect_flip(
     mut commands: Commands,
     card_query: Query<(Entity, &Card, &Transform, Option<&PreviousCardPosition>, Option<&PreviousFaceUp>)>,
@@ -43,25 +44,11 @@ pub fn detect_flip(
         }
 
         // detect when the card must animate
-        let should_animate = match (prev_pos, &card.position) {
-            _ if !prev_face && card.face_up && matches!(card.position, CardPosition::Hand(_)) => true,
-            _ => false,
-        };
+        let should_animate = !prev_face && card.face_up && matches!(card.position, CardPosition::Hand(_));
         
         if should_animate {
             // insert animation
-            commands.entity(entity).insert(CardAnimation {
-                animation_type: AnimationType::Flip,
-                progress: 0.0,
-                duration: 0.4,
-                state: AnimationState::Animating,
-                original_scale: transform.scale,
-                original_position: transform.translation,
-                original_rotation: transform.rotation,
-                target_position: Some(transform.translation),
-                delay: 0.0,
-                delay_elapsed: 0.0,
-            });
+            commands.entity(entity).insert(flip_animation(transform));
         }
         
         // update previous position if it changes
@@ -73,61 +60,3 @@ pub fn detect_flip(
         }
     }
 }
-
-pub fn animate_flip(
-    mut commands: Commands,
-    mut card_query: Query<(Entity, &mut Transform, &mut CardAnimation), With<CardAnimation>>,
-    time: Res<Time>,   
-) {
-    for (entity, mut transform, mut animation) in card_query.iter_mut() {
-        if animation.animation_type == AnimationType::Flip {
-            let progress = animation.progress;
-
-            if progress <= 0.5 {
-                let t = progress * 2.0;  // Normalizar a 0.0-1.0
-    
-                // X size: compress horizontally (simulates rotation)
-                transform.scale.x = animation.original_scale.x * (1.0 - t).max(0.05);
-
-                // Y size: smaller (perspective)
-                transform.scale.y = animation.original_scale.y * (1.0 - t * 0.15);
-
-                // Z rotation: tilt from corner ("page" effect)
-                let rotation_angle = -t * 0.5;  // Rotar hasta ~17 grados
-                transform.rotation = Quat::from_rotation_z(rotation_angle);
-
-                // move to bottom right corner
-                transform.translation.x = animation.original_position.x + (t * 25.0);
-                transform.translation.y = animation.original_position.y - (t * 25.0);
-                transform.translation.z = animation.original_position.z + (t * 5.0);
-            } else {
-                // get back to normal
-                let t = (progress - 0.5) * 2.0;
-
-                // X size: expand to normal
-                transform.scale.x = animation.original_scale.x * (t).max(0.05);
-
-                // Y size: expand to normal
-                transform.scale.y = animation.original_scale.y * (0.85 + t * 0.15);
-
-                // Z rotation: return to 0
-                let rotation_angle = -0.5 * (1.0 - t);
-                transform.rotation = Quat::from_rotation_z(rotation_angle);
-
-                // return to normal position
-                transform.translation.x = animation.original_position.x + (25.0 * (1.0 - t));
-                transform.translation.y = animation.original_position.y - (25.0 * (1.0 - t));
-                transform.translation.z = animation.original_position.z + ((1.0 - t) * 5.0);
-            }
-
-            animation.progress += time.delta_secs() / animation.duration;
-            
-            if animation.progress >= 1.0 {
-                transform.scale = animation.original_scale;
-                transform.translation = animation.original_position;
-                transform.rotation = animation.original_rotation;
-                commands.entity(entity).remove::<CardAnimation>();
-            }
-        }
-    }
-}
\ No newline at end of file
@@ -1,19 +1,21 @@
 use bevy::prelude::*;
-use crate::ui::card_animation::animation::flip::{detect_flip, animate_flip};
-use crate::ui::card_animation::animation::movement::{detect_movement, animate_movement};
-use crate::ui::card_animation::animation::deal::animate_deal;
+use crate::ui::card_animation::animation::flip::detect_flip;
+use crate::ui::card_animation::animation::movement::{detect_movement, finish_movement};
+use crate::ui::card_animation::animation::deal::finish_deal;
+use crate::ui::card_animation::animation::sound::animation_sounds;
+use crate::ui::tween::TweenSet;
 
 mod flip;
 mod deal;
 mod movement;
+mod sound;
 
 pub struct AnimatePlugin;
 
 impl Plugin for AnimatePlugin {
     fn build(&self, app: &mut App) {
         app
-        .add_systems(Update, (detect_flip, detect_movement))
-        .add_systems(Update, (animate_flip, animate_movement).after(detect_flip).after(detect_movement))
-        .add_systems(Update, animate_deal);
+        .add_systems(Update, (detect_flip, detect_movement).before(TweenSet))
+        .add_systems(Update, (finish_deal, finish_movement, animation_sounds).after(TweenSet));
     }
-}
\ No newline at end of file
+}
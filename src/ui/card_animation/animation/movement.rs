@@ -1,16 +1,20 @@
 use bevy::prelude::*;
-use bevy::window::PrimaryWindow;
+use crate::game::table::resource::TableLayout;
 use crate::game::card::component::{Card, PreviousCardPosition, CardPosition, PreviousTranslation};
-use crate::ui::card_animation::component::{CardAnimation, AnimationState, AnimationType};
+use crate::ui::card_animation::component::{CardAnimation, MOVEMENT_TIME};
+use crate::ui::tween::component::{Tween, TweenCompleted, TweenKind};
 use crate::game::graveyard::component::Graveyard;
+use crate::ui::soundtrack::resource::CardSound;
 
 // detect card movement
+#[allow(clippy::type_complexity)]
 pub fn detect_movement(
     mut commands: Commands,
     mut card_query: Query<(Entity, &Card, &mut Transform, Option<&PreviousCardPosition>, Option<&PreviousTranslation>)>,
     animation_query: Query<&CardAnimation>,
-    windows: Query<&Window, With<PrimaryWindow>>,
+    layout: Res<TableLayout>,
     graveyard_query: Query<&Graveyard>,
+    mut sounds: MessageWriter<CardSound>,
 ) {
     for (entity, card, mut transform, previous_pos, previous_translation) in card_query.iter_mut() {
         // verify if is animating already
@@ -47,45 +51,29 @@ pub fn detect_movement(
         }
 
         // detect when the card must animate
-        let should_animate_movement = match (prev_pos, &card.position) {
-            (CardPosition::Deck, CardPosition::DrawnCard(_)) => true,
-            (CardPosition::DrawnCard(_), CardPosition::Graveyard) => true,
-            (CardPosition::DrawnCard(_), CardPosition::Hand(_)) => true,
-            (CardPosition::Hand(_), CardPosition::Graveyard) => true,
-            (CardPosition::Graveyard, CardPosition::DrawnCard(_)) => true,
-            _ => false,
-        };
+        let should_animate_movement = matches!((prev_pos, &card.position),
+            (CardPosition::Deck, CardPosition::DrawnCard(_))
+            | (CardPosition::DrawnCard(_), CardPosition::Graveyard)
+            | (CardPosition::DrawnCard(_), CardPosition::Hand(_))
+            | (CardPosition::Hand(_), CardPosition::Graveyard)
+            | (CardPosition::Graveyard, CardPosition::DrawnCard(_))
+        );
     
         if should_animate_movement {
-            let Ok(window) = windows.single() else { continue; };
+            sounds.write(match &card.position {
+                CardPosition::DrawnCard(_) => CardSound::Draw,
+                CardPosition::Graveyard => CardSound::Discard,
+                _ => CardSound::Swap,
+            });
 
             let target_pos = match &card.position {
-                CardPosition::DrawnCard(_) => Vec3::new(window.width() * 0.1, 0.0, 30.0),
+                CardPosition::DrawnCard(_) => layout.drawn_card_position(),
                 CardPosition::Graveyard => {
-                    // Calculate stacking offset for graveyard
-                    if let Ok(graveyard) = graveyard_query.single() {
-                        let stack_index = graveyard.cards.iter()
-                            .position(|&e| e == entity)
-                            .unwrap_or(graveyard.cards.len() - 1) as f32;
-                        
-                        let max_stack = 15.0; // max cards to stack
-                        
-                        // stack offset effect
-                        let (offset_x, offset_y) = if stack_index < max_stack {
-                            (stack_index * 0.5, stack_index * 0.3)
-                        } else {
-                            (max_stack * 0.5, max_stack * 0.3)
-                        };
-                        
-                        Vec3::new(
-                            window.width() * -0.06 + offset_x,
-                            window.height() * 0.0 + offset_y,
-                            10.0 + stack_index
-                        )
-                    } else {
-                        // keep position if it is not among the first
-                        Vec3::new(window.width() * -0.06, 0.0, 10.0)
-                    }
+                    // stack the card over the others of the graveyard
+                    let depth = graveyard_query.single().ok()
+                        .and_then(|graveyard| graveyard.cards.iter().position(|&e| e == entity))
+                        .unwrap_or(0);
+                    layout.graveyard_stack_position(depth)
                 }
                 CardPosition::Hand(_) => {
                     // to hand, keep original position
@@ -97,19 +85,13 @@ pub fn detect_movement(
 
             transform.translation = prev_trans; // move the card to original position before animating
 
-            // insert movement animation
-            commands.entity(entity).insert(CardAnimation {
-                animation_type: AnimationType::Movement,
-                progress: 0.0,
-                duration: 0.25,
-                state: AnimationState::Animating,
-                original_position: prev_trans,
-                original_scale: transform.scale,
-                original_rotation: transform.rotation,
-                target_position: Some(target_pos),
-                delay: 0.0,
-                delay_elapsed: 0.0,
-            });
+            // insert movement animation, cards land on the graveyard a bit rotated
+            let mut tween = vec![Tween::translation(prev_trans, target_pos, MOVEMENT_TIME)];
+            if matches!(card.position, CardPosition::Graveyard) {
+                let random_rotation = (rand::random::<f32>() - 0.5) * 0.15;
+                tween.push(Tween::rotation(transform.rotation, Quat::from_rotation_z(random_rotation), MOVEMENT_TIME));
+            }
+            commands.entity(entity).insert(CardAnimation::new(TweenKind::Movement, Tween::parallel(tween)));
         }
 
         // update previous position/translation
@@ -122,36 +104,14 @@ pub fn detect_movement(
     }
 }
 
-pub fn animate_movement(
-    mut commands: Commands,
-    mut card_query: Query<(Entity, &mut Transform, &mut CardAnimation, &mut Card)>,
-    time: Res<Time>,
+// the card is in its new place
+pub fn finish_movement(
+    mut completed: MessageReader<TweenCompleted>,
+    mut card_query: Query<&mut Card>,
 ) {
-    for (entity, mut transform, mut animation, mut card) in card_query.iter_mut() {
-        if animation.animation_type == AnimationType::Movement {
-            if let Some(target) = animation.target_position {
-                
-                // Interpolate from original_position to new position
-                let t = animation.progress;
-                transform.translation = animation.original_position.lerp(target, t);
-
-                // update progress
-                animation.progress += time.delta_secs() / animation.duration;
-                
-                // when the progress has finished, remove card animation
-                if animation.progress >= 1.0 {
-                    card.is_being_dealt = false;
-                    transform.translation = target;
-
-                    // Si es graveyard, agregar rotación aleatoria
-                    if matches!(card.position, CardPosition::Graveyard) {
-                        let random_rotation = (rand::random::<f32>() - 0.5) * 0.15;
-                        transform.rotation = Quat::from_rotation_z(random_rotation);
-                    }
-
-                    commands.entity(entity).remove::<CardAnimation>();
-                }
-            }
+    for event in completed.read().filter(|event| event.kind == TweenKind::Movement) {
+        if let Ok(mut card) = card_query.get_mut(event.entity) {
+            card.is_being_dealt = false;
         }
     }
-}
\ No newline at end of file
+}
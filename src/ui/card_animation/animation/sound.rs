@@ -0,0 +1,18 @@
+use bevy::prelude::*;
+use crate::ui::card_animation::component::SOUND_MARK;
+use crate::ui::soundtrack::resource::CardSound;
+use crate::ui::tween::component::{TweenKind, TweenMarker};
+
+// deal and flip sounds play when their animation really starts (after the deal delay)
+pub fn animation_sounds(
+    mut markers: MessageReader<TweenMarker>,
+    mut sounds: MessageWriter<CardSound>,
+) {
+    for marker in markers.read().filter(|marker| marker.name == SOUND_MARK) {
+        match marker.kind {
+            TweenKind::Deal => { sounds.write(CardSound::Deal); }
+            TweenKind::Flip => { sounds.write(CardSound::Flip); }
+            _ => {}
+        }
+    }
+}
@@ -1,29 +1,110 @@
 use bevy::prelude::*;
+use crate::ui::tween::component::{Animator, Motion, Tween, TweenKind, Ease};
 
-#[derive(Component, PartialEq)]
-pub struct CardAnimation {
-    pub animation_type: AnimationType,
-    pub progress: f32,
-    pub duration: f32,
-    pub state: AnimationState,
-    pub original_scale: Vec3,
-    pub original_position: Vec3,
-    pub original_rotation: Quat,
-    pub target_position: Option<Vec3>, 
-    pub delay: f32,
-    pub delay_elapsed: f32,
+// deal, flip or movement of a card, see ui/tween
+pub type CardAnimation = Animator<Motion>;
+
+// mark of the tween where the sound of the animation plays
+pub const SOUND_MARK: &str = "sound";
+
+const DEAL_TIME: f32 = 0.3;
+const FLIP_TIME: f32 = 0.4;
+pub const MOVEMENT_TIME: f32 = 0.25;
+
+// hidden under the table during the delay, then flies from the deck to its place
+pub fn deal_animation(from: Vec3, to: Vec3, delay: f32) -> CardAnimation {
+    let hidden = from.with_z(-10.0);
+    CardAnimation::new(TweenKind::Deal, Tween::sequence([
+        Tween::parallel([
+            Tween::translation(hidden, hidden, delay),
+            Tween::alpha(0.0, 0.0, delay),
+        ]),
+        Tween::mark(SOUND_MARK),
+        Tween::parallel([
+            Tween::alpha(1.0, 1.0, 0.0),
+            // visible over the other cards during the animation
+            Tween::translation(from.with_z(100.0), to.with_z(100.0), DEAL_TIME).ease(Ease::SmoothStep),
+        ]),
+        Tween::translation(to.with_z(10.0), to.with_z(10.0), 0.0),
+    ]))
+}
+
+// "page" effect: squeeze and tilt towards the bottom right corner, then back (the face changes at the half)
+pub fn flip_animation(transform: &Transform) -> CardAnimation {
+    let (position, rotation, scale) = (transform.translation, transform.rotation, transform.scale);
+    let squeezed = Vec3::new(scale.x * 0.05, scale.y * 0.85, scale.z);
+    let tilted = rotation * Quat::from_rotation_z(-0.5); // ~30 degrees
+    let corner = position + Vec3::new(25.0, -25.0, 5.0);
+    let half = FLIP_TIME / 2.0;
+
+    CardAnimation::new(TweenKind::Flip, Tween::sequence([
+        Tween::mark(SOUND_MARK),
+        Tween::parallel([
+            Tween::scale(scale, squeezed, half).ease(Ease::QuadIn),
+            Tween::rotation(rotation, tilted, half).ease(Ease::QuadIn),
+            Tween::translation(position, corner, half).ease(Ease::QuadIn),
+        ]),
+        Tween::parallel([
+            Tween::scale(squeezed, scale, half).ease(Ease::QuadOut),
+            Tween::rotation(tilted, rotation, half).ease(Ease::QuadOut),
+            Tween::translation(corner, position, half).ease(Ease::QuadOut),
+        ]),
+    ]))
 }
 
-#[derive(PartialEq)]
-pub enum AnimationType {
-    Flip,
-    Movement,
-    Deal,
+pub fn movement_animation(from: Vec3, to: Vec3, duration: f32, ease: Ease) -> CardAnimation {
+    CardAnimation::new(TweenKind::Movement, Tween::translation(from, to, duration).ease(ease))
 }
 
-#[derive(PartialEq)]
-pub enum AnimationState {
-    // Idle,
-    Animating,
-    WaitingToStart,
-}
\ No newline at end of file
+pub const SWAP_TIME: f32 = 0.45;
+// height of the swap arc, fraction of the distance between the cards
+pub const SWAP_BEND: f32 = 0.25;
+const ARC_STEPS: usize = 8;
+
+const GATHER_TIME: f32 = 0.2;
+const ORBIT_STEP_TIME: f32 = 0.1;
+const SPREAD_TIME: f32 = 0.25;
+
+// point of the arc from -> to, t = 0.0 - 1.0 (quadratic curve bent to the left of the path)
+pub fn arc_point(from: Vec3, to: Vec3, bend: f32, t: f32) -> Vec3 {
+    let path = (to - from).truncate();
+    let control = from.lerp(to, 0.5) + (path.perp() * bend * 2.0).extend(0.0);
+    from.lerp(control, t).lerp(control.lerp(to, t), t)
+}
+
+// swapped cards fly over the others along an arc, in opposite sides so they cross
+pub fn arc_animation(from: Vec3, to: Vec3, duration: f32) -> CardAnimation {
+    let lift = from.z.max(to.z) + 50.0;
+    let step = duration / ARC_STEPS as f32;
+    let point = |i: usize| arc_point(from, to, SWAP_BEND, Ease::QuadInOut.apply(i as f32 / ARC_STEPS as f32)).with_z(lift);
+
+    let mut steps: Vec<Tween> = (0..ARC_STEPS)
+        .map(|i| Tween::translation(point(i), point(i + 1), step))
+        .collect();
+    steps.push(Tween::translation(to, to, 0.0));
+    CardAnimation::new(TweenKind::Movement, Tween::sequence(steps))
+}
+
+// shuffled hand: the cards gather, spin a whole turn around the center of the hand and go to their new slot
+pub fn swirl_animation(from: Vec3, to: Vec3, center: Vec3, radius: f32, rest: Quat) -> CardAnimation {
+    let start = (from - center).truncate();
+    let start_angle = start.y.atan2(start.x);
+    let point = |k: usize| {
+        let angle = start_angle + k as f32 * std::f32::consts::FRAC_PI_2;
+        center.with_z(from.z) + Vec3::new(angle.cos(), angle.sin(), 0.0) * radius
+    };
+    let turn = |k: usize| rest * Quat::from_rotation_z(k as f32 * std::f32::consts::FRAC_PI_2);
+
+    let mut steps = vec![Tween::translation(from, point(0), GATHER_TIME).ease(Ease::QuadIn)];
+    for k in 0..4 {
+        steps.push(Tween::parallel([
+            Tween::translation(point(k), point(k + 1), ORBIT_STEP_TIME),
+            Tween::rotation(turn(k), turn(k + 1), ORBIT_STEP_TIME),
+        ]));
+    }
+    steps.push(Tween::parallel([
+        Tween::translation(point(4), to, SPREAD_TIME).ease(Ease::QuadOut),
+        Tween::rotation(rest, rest, 0.0),
+    ]));
+    CardAnimation::new(TweenKind::Shuffle, Tween::sequence(steps))
+}
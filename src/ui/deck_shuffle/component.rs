@@ -0,0 +1,21 @@
+use bevy::prelude::*;
+use crate::global::settings::resource::Settings;
+
+// riffles of the round start shuffle, timed to audio/card-shuffle.ogg (~3s)
+pub const RIFFLES: usize = 2;
+pub const SPLIT_TIME: f32 = 0.35; // deck cut in two piles
+pub const RIFFLE_GAP: f32 = 0.1;
+pub const RIFFLE_WINDOW: f32 = 0.9; // cards fall back one by one during this time
+pub const FALL_TIME: f32 = 0.15;
+pub const RIFFLE_TIME: f32 = SPLIT_TIME + RIFFLE_GAP + RIFFLE_WINDOW + FALL_TIME;
+
+// seconds (at normal speed) the deal waits for the shuffle
+pub fn shuffle_intro_time(settings: &Settings) -> f32 {
+    if settings.reduced_motion { 0.0 } else { RIFFLES as f32 * RIFFLE_TIME }
+}
+
+// shuffle playing at the start of a round, click to skip
+#[derive(Resource)]
+pub struct ShuffleIntro {
+    pub remaining: f32,
+}
@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+use crate::game::gamestate::{AppState, PauseState};
+use crate::game::hand::system::deal_initial_hands;
+use crate::ui::deck_shuffle::system::{start_shuffle_intro, update_shuffle_intro};
+use crate::ui::tween::TweenSet;
+
+pub mod component;
+mod system;
+
+pub struct DeckShufflePlugin;
+
+impl Plugin for DeckShufflePlugin {
+    fn build(&self, app: &mut App) {
+        app
+        // the dealt cards are already out of the deck
+        .add_systems(OnEnter(AppState::Setup), start_shuffle_intro.after(deal_initial_hands))
+        .add_systems(Update, update_shuffle_intro.before(TweenSet).run_if(in_state(PauseState::Running)));
+    }
+}
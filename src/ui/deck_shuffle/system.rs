@@ -0,0 +1,111 @@
+use bevy::prelude::*;
+use crate::game::card::component::{Card, CardPosition, CARD_SIZE};
+use crate::global::input::{resource::GameAction, system::ActionInput};
+use crate::global::settings::resource::Settings;
+use crate::ui::card_animation::component::CardAnimation;
+use crate::ui::deck_shuffle::component::{
+    ShuffleIntro, shuffle_intro_time, RIFFLES, SPLIT_TIME, RIFFLE_GAP, RIFFLE_WINDOW, FALL_TIME,
+};
+use crate::ui::soundtrack::resource::{CardSound, CardSoundPlayer};
+use crate::ui::tween::component::{Ease, Tween, TweenKind};
+
+// riffle of one card: to its pile, wait for its turn and fall back over the cards below it
+fn riffle_tween(transform: &Transform, depth: usize, total: usize) -> Tween {
+    let base = transform.translation;
+    let rest = transform.rotation;
+
+    // even cards to the left pile, odd to the right, so both piles interleave when falling
+    let direction = if depth.is_multiple_of(2) { -1.0 } else { 1.0 };
+    let pile = base + Vec3::X * direction * CARD_SIZE.x * transform.scale.x * 0.6;
+    let tilt = rest * Quat::from_rotation_z(-direction * 0.08);
+
+    // bottom cards fall first, so the z order is already right
+    let turn = depth as f32 / total.max(1) as f32 * RIFFLE_WINDOW;
+
+    let riffle = Tween::sequence([
+        Tween::parallel([
+            Tween::translation(base, pile, SPLIT_TIME).ease(Ease::QuadOut),
+            Tween::rotation(rest, tilt, SPLIT_TIME).ease(Ease::QuadOut),
+        ]),
+        Tween::delay(RIFFLE_GAP + turn),
+        Tween::parallel([
+            Tween::translation(pile, base, FALL_TIME).ease(Ease::QuadIn),
+            Tween::rotation(tilt, rest, FALL_TIME).ease(Ease::QuadIn),
+        ]),
+        // every card waits the whole window, the next riffle starts together
+        Tween::delay(RIFFLE_WINDOW - turn),
+    ]);
+    Tween::sequence(std::iter::repeat_n(riffle, RIFFLES))
+}
+
+// riffle the cards left in the deck while the dealt ones wait (their deal is delayed by the same time)
+pub fn start_shuffle_intro(
+    mut commands: Commands,
+    card_query: Query<(Entity, &Card, &Transform)>,
+    settings: Res<Settings>,
+    mut sounds: MessageWriter<CardSound>,
+) {
+    let duration = shuffle_intro_time(&settings);
+    if duration <= 0.0 {
+        return;
+    }
+
+    let mut deck: Vec<(Entity, &Transform)> = card_query.iter()
+        .filter(|(_, card, _)| card.position == CardPosition::Deck)
+        .map(|(entity, _, transform)| (entity, transform))
+        .collect();
+    deck.sort_by(|a, b| a.1.translation.z.total_cmp(&b.1.translation.z));
+
+    let total = deck.len();
+    for (depth, (entity, transform)) in deck.into_iter().enumerate() {
+        commands.entity(entity).insert(CardAnimation::new(TweenKind::Shuffle, riffle_tween(transform, depth, total)));
+    }
+
+    commands.insert_resource(ShuffleIntro { remaining: duration });
+    sounds.write(CardSound::Shuffle);
+    info!(target: "mygame", "Shuffling {} cards", total);
+}
+
+// count down the shuffle, a click (or confirm) jumps to the deal
+#[allow(clippy::too_many_arguments)]
+pub fn update_shuffle_intro(
+    mut commands: Commands,
+    intro: Option<ResMut<ShuffleIntro>>,
+    mut mouse_input: ResMut<ButtonInput<MouseButton>>,
+    input: ActionInput,
+    time: Res<Time>,
+    settings: Res<Settings>,
+    mut animation_query: Query<&mut CardAnimation>,
+    sound_query: Query<(Entity, &CardSoundPlayer)>,
+) {
+    let Some(mut intro) = intro else { return; };
+
+    let skip = mouse_input.just_pressed(MouseButton::Left) || input.just_pressed(GameAction::Confirm);
+    if skip {
+        // the skip press is consumed, it must not reach the board click handlers
+        mouse_input.clear_just_pressed(MouseButton::Left);
+        for mut animation in animation_query.iter_mut() {
+            match animation.kind {
+                TweenKind::Shuffle => animation.elapsed = animation.tween.duration(),
+                // the deal was waiting for the shuffle
+                TweenKind::Deal => animation.elapsed += intro.remaining,
+                _ => {}
+            }
+        }
+
+        // the sound would keep going without the animation
+        for (entity, player) in sound_query.iter() {
+            if player.0 == CardSound::Shuffle {
+                commands.entity(entity).despawn();
+            }
+        }
+        intro.remaining = 0.0;
+        info!(target: "mygame", "Shuffle skipped");
+    } else {
+        intro.remaining -= time.delta_secs() * settings.animation_speed;
+    }
+
+    if intro.remaining <= 0.0 {
+        commands.remove_resource::<ShuffleIntro>();
+    }
+}
@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+
+// fanned list of the discarded cards, over the table
+#[derive(Component)]
+pub struct DiscardHistoryUI;
+
+// open while the pile is hovered (mouse) or held (touch)
+#[derive(Resource, Default)]
+pub struct DiscardHistory {
+    pub hover_time: f32,
+    pub press_time: f32,
+    pub open: bool,
+    pub offset: usize, // newest cards skipped, moved with the mouse wheel
+    pub shown: Option<(Vec<Entity>, usize)>, // pile and offset of the spawned overlay
+}
+
+pub const HOVER_DELAY: f32 = 0.35; // seconds
+pub const LONG_PRESS: f32 = 0.5;
+pub const PAGE: usize = 10; // cards shown at once
+pub const FAN_ANGLE: f32 = 12.0; // degrees of the cards at both ends
+pub const FAN_LIFT: f32 = 14.0; // px the middle of the fan rises over the ends
+
+pub const ENTRY_SIZE: Vec2 = Vec2::new(54.0, 81.0);
+pub const TOP_CARD_BORDER: Color = Color::srgb(1.0, 0.85, 0.3);
@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+use crate::game::gamestate::{AppState, PauseState};
+use crate::ui::discard_history::component::DiscardHistory;
+use crate::ui::discard_history::system::{track_discard_history, show_discard_history, close_discard_history};
+
+pub mod component;
+mod system;
+
+pub struct DiscardHistoryPlugin;
+
+impl Plugin for DiscardHistoryPlugin {
+    fn build(&self, app: &mut App) {
+        app
+        .init_resource::<DiscardHistory>()
+        .add_systems(Update, (track_discard_history, show_discard_history).chain()
+            .run_if(in_state(AppState::PlayerTurn)).run_if(in_state(PauseState::Running)))
+        .add_systems(OnExit(AppState::PlayerTurn), close_discard_history)
+        .add_systems(OnEnter(PauseState::Paused), close_discard_history);
+    }
+}
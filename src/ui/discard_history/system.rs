@@ -0,0 +1,220 @@
+use bevy::input::mouse::MouseWheel;
+use bevy::picking::hover::HoverMap;
+use bevy::picking::pointer::PointerId;
+use bevy::prelude::*;
+use crate::game::card::component::{Card, CardDrag, CardHandles, CardPosition};
+use crate::game::gamestate::GameEntity;
+use crate::game::graveyard::component::{Graveyard, Discarded};
+use crate::game::player::component::Player;
+use crate::global::locale::resource::Locale;
+use crate::global::settings::resource::Settings;
+use crate::ui::discard_history::component::{
+    DiscardHistory, DiscardHistoryUI, HOVER_DELAY, LONG_PRESS, PAGE, FAN_ANGLE, FAN_LIFT, ENTRY_SIZE, TOP_CARD_BORDER,
+};
+use crate::ui::widget::component::BUTTON_BORDER;
+
+// open/close the history and browse it with the mouse wheel
+#[allow(clippy::too_many_arguments)]
+pub fn track_discard_history(
+    mut history: ResMut<DiscardHistory>,
+    hover_map: Res<HoverMap>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    mut wheel: MessageReader<MouseWheel>,
+    graveyard_query: Query<(Entity, &Graveyard)>,
+    card_query: Query<&Card>,
+    drag: Res<CardDrag>,
+    settings: Res<Settings>,
+    time: Res<Time>,
+) {
+    let Ok((graveyard_entity, graveyard)) = graveyard_query.single() else { return; };
+    let on_pile = |entity: &Entity| {
+        *entity == graveyard_entity || card_query.get(*entity).is_ok_and(|card| card.position == CardPosition::Graveyard)
+    };
+
+    // the mouse hovers, a touch only counts while the finger is on the pile
+    let hovered = hover_map.get(&PointerId::Mouse).is_some_and(|hits| hits.keys().any(on_pile));
+    let touched = hover_map.iter()
+        .filter(|(pointer, _)| pointer.is_touch())
+        .any(|(_, hits)| hits.keys().any(on_pile));
+
+    let delta = time.delta_secs();
+    history.hover_time = if hovered { history.hover_time + delta } else { 0.0 };
+    let pressed = touched || (hovered && mouse_input.pressed(MouseButton::Left));
+    history.press_time = if pressed { history.press_time + delta } else { 0.0 };
+
+    let available = settings.discard_history && !graveyard.cards.is_empty() && drag.entity.is_none();
+    let open = available && (hovered || touched)
+        && (history.hover_time >= HOVER_DELAY || history.press_time >= LONG_PRESS);
+
+    if !open {
+        history.open = false;
+        history.offset = 0;
+        wheel.clear();
+        return;
+    }
+    history.open = true;
+
+    // up: older cards, down: back to the newest
+    let scroll: f32 = wheel.read().map(|event| event.y).sum();
+    let max_offset = graveyard.cards.len().saturating_sub(PAGE);
+    if scroll > 0.0 {
+        history.offset = (history.offset + 1).min(max_offset);
+    } else if scroll < 0.0 {
+        history.offset = history.offset.saturating_sub(1);
+    }
+    history.offset = history.offset.min(max_offset);
+}
+
+// spawn the overlay for the current page, rebuilt when the pile or the page changes
+#[allow(clippy::too_many_arguments)]
+pub fn show_discard_history(
+    mut commands: Commands,
+    mut history: ResMut<DiscardHistory>,
+    ui_query: Query<Entity, With<DiscardHistoryUI>>,
+    graveyard_query: Query<&Graveyard>,
+    card_query: Query<(&Card, Option<&Discarded>)>,
+    player_query: Query<&Player>,
+    handles: Option<Res<CardHandles>>,
+    settings: Res<Settings>,
+    locale: Res<Locale>,
+) {
+    let page = graveyard_query.single().ok()
+        .filter(|_| history.open)
+        .map(|graveyard| (graveyard.cards.clone(), history.offset));
+
+    if history.shown == page && !locale.is_changed() {
+        return;
+    }
+    for entity in ui_query.iter() {
+        commands.entity(entity).despawn();
+    }
+    history.shown = page.clone();
+
+    let Some((cards, offset)) = page else { return; };
+    let Some(handles) = handles else { return; };
+
+    // oldest on the left, the top card of the pile on the right
+    let end = cards.len() - offset;
+    let start = end.saturating_sub(PAGE);
+    let shown = &cards[start..end];
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Percent(3.0),
+            left: Val::Percent(10.0),
+            width: Val::Percent(80.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(10.0),
+            padding: UiRect::all(Val::Px(12.0)),
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.8)),
+        BorderColor::all(BUTTON_BORDER),
+        DiscardHistoryUI,
+        GameEntity,
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new(locale.format("history.title", &[("from", &(start + 1)), ("to", &end), ("total", &cards.len())])),
+            TextFont { font_size: 20.0, ..default() },
+            TextColor(Color::WHITE),
+        ));
+
+        parent.spawn(Node {
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::FlexEnd,
+            column_gap: Val::Px(6.0),
+            padding: UiRect::vertical(Val::Px(FAN_LIFT)),
+            ..default()
+        }).with_children(|fan| {
+            for (i, &card_entity) in shown.iter().enumerate() {
+                let Ok((card, discarded)) = card_query.get(card_entity) else { continue; };
+
+                // -1.0 on the left end, 1.0 on the right one
+                let side = if shown.len() > 1 { i as f32 / (shown.len() - 1) as f32 * 2.0 - 1.0 } else { 0.0 };
+                let is_top = offset == 0 && i == shown.len() - 1;
+                let front = handles.front(card.suit, card.value);
+
+                let player = discarded.and_then(|discarded| player_query.get(discarded.player).ok())
+                    .map(|player| player.name.clone())
+                    .unwrap_or_default();
+                let turn = discarded.map(|discarded| locale.format("history.turn", &[("turn", &discarded.turn)]))
+                    .unwrap_or_default();
+
+                fan.spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        row_gap: Val::Px(2.0),
+                        width: Val::Px(ENTRY_SIZE.x + 16.0),
+                        ..default()
+                    },
+                    UiTransform {
+                        translation: Val2::px(0.0, FAN_LIFT * side * side),
+                        rotation: Rot2::degrees(FAN_ANGLE * side),
+                        ..default()
+                    },
+                )).with_children(|entry| {
+                    entry.spawn((
+                        ImageNode {
+                            image: front.image.clone(),
+                            texture_atlas: front.atlas.clone(),
+                            ..default()
+                        },
+                        Node {
+                            width: Val::Px(ENTRY_SIZE.x),
+                            height: Val::Px(ENTRY_SIZE.y),
+                            border: UiRect::all(Val::Px(if is_top { 3.0 } else { 0.0 })),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        BorderColor::all(TOP_CARD_BORDER),
+                    )).with_children(|face| {
+                        // value written on the front, like on the table (no art or high contrast)
+                        if front.label || settings.high_contrast {
+                            let color = if card.suit.is_red() { Color::srgb(0.75, 0.1, 0.1) } else { Color::srgb(0.1, 0.1, 0.1) };
+                            face.spawn((
+                                Text::new(locale.card_short(card.value, card.suit)),
+                                TextFont { font_size: 22.0, ..default() },
+                                TextColor(color),
+                            ));
+                        }
+                    });
+                    entry.spawn((
+                        Text::new(player),
+                        TextFont { font_size: 12.0, ..default() },
+                        TextColor(Color::WHITE),
+                    ));
+                    entry.spawn((
+                        Text::new(turn),
+                        TextFont { font_size: 11.0, ..default() },
+                        TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                    ));
+                });
+            }
+        });
+
+        if cards.len() > PAGE {
+            parent.spawn((
+                Text::new(locale.get("history.hint")),
+                TextFont { font_size: 14.0, ..default() },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+        }
+    });
+}
+
+// the history only lives during the turns
+pub fn close_discard_history(
+    mut commands: Commands,
+    mut history: ResMut<DiscardHistory>,
+    ui_query: Query<Entity, With<DiscardHistoryUI>>,
+) {
+    for entity in ui_query.iter() {
+        commands.entity(entity).despawn();
+    }
+    *history = DiscardHistory::default();
+}
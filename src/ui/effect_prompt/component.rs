@@ -0,0 +1,18 @@
+use bevy::prelude::*;
+
+// banner telling what to pick for the active special effect
+#[derive(Component)]
+pub struct EffectPromptUI;
+
+#[derive(Component)]
+pub struct EffectPromptText;
+
+#[derive(Component)]
+pub struct CancelEffectButton;
+
+// glow behind a card that can be picked
+#[derive(Component)]
+pub struct TargetHighlight(pub Entity);
+
+pub const TARGET_COLOR: Color = Color::srgba(0.4, 0.85, 1.0, 0.8);
+pub const DIMMED_OPACITY: f32 = 0.35;
@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+use crate::game::gamestate::AppState;
+use crate::game::card::system::card_visual;
+use crate::ui::effect_prompt::system::{update_effect_prompt, handle_cancel_button, highlight_targets, clean_effect_prompt};
+
+pub mod component;
+mod system;
+
+pub struct EffectPromptPlugin;
+
+impl Plugin for EffectPromptPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(Update, (update_effect_prompt, handle_cancel_button).run_if(in_state(AppState::PlayerTurn)))
+            // after the turn indicator opacity and card positions
+            .add_systems(Update, highlight_targets.after(card_visual).run_if(in_state(AppState::PlayerTurn)))
+            .add_systems(OnExit(AppState::PlayerTurn), clean_effect_prompt);
+    }
+}
@@ -0,0 +1,149 @@
+use bevy::prelude::*;
+use crate::game::card::component::{Card, CARD_SIZE};
+use crate::game::gamestate::GameEntity;
+use crate::game::special_cards::resource::{SpecialCardEffect, SpecialEffect, CancelSpecialEffect};
+use crate::game::turn_player::component::Turn;
+use crate::global::input::resource::GameAction;
+use crate::global::settings::resource::Settings;
+use crate::global::locale::resource::Locale;
+use crate::ui::effect_prompt::component::{EffectPromptUI, EffectPromptText, CancelEffectButton, TargetHighlight, TARGET_COLOR};
+use crate::ui::widget::component::BUTTON_BORDER;
+use crate::ui::widget::system::spawn_localized_button;
+
+// what the player has to pick now (string table key)
+fn prompt_key(effect: &SpecialCardEffect) -> Option<&'static str> {
+    match effect.effect_type {
+        Some(SpecialEffect::Swap) if effect.awaiting_target => Some("prompt.swap_target"),
+        Some(SpecialEffect::Swap) if effect.awaiting_own_card => Some("prompt.swap_own"),
+        Some(SpecialEffect::Shuffle) if effect.awaiting_target => Some("prompt.shuffle_target"),
+        _ => None,
+    }
+}
+
+// show the banner while the effect waits for targets
+pub fn update_effect_prompt(
+    mut commands: Commands,
+    special_effect: Option<Res<SpecialCardEffect>>,
+    prompt_query: Query<Entity, With<EffectPromptUI>>,
+    mut text_query: Query<&mut Text, With<EffectPromptText>>,
+    settings: Res<Settings>,
+    locale: Res<Locale>,
+) {
+    let prompt = special_effect.as_deref().and_then(prompt_key).map(|key| locale.get(key));
+
+    let Some(prompt) = prompt else {
+        for entity in prompt_query.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    };
+
+    // update the step (swap asks twice)
+    if !prompt_query.is_empty() {
+        for mut text in text_query.iter_mut() {
+            if text.0 != prompt {
+                text.0 = prompt.clone();
+            }
+        }
+        return;
+    }
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Percent(3.0),
+            left: Val::Percent(25.0),
+            width: Val::Percent(50.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(8.0),
+            padding: UiRect::all(Val::Px(12.0)),
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.75)),
+        BorderColor::all(BUTTON_BORDER),
+        EffectPromptUI,
+        GameEntity,
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new(prompt),
+            TextFont { font_size: 26.0, ..default() },
+            TextColor(Color::WHITE),
+            TextLayout::new_with_justify(Justify::Center),
+            EffectPromptText,
+        ));
+        parent.spawn((
+            Text::new(locale.format("prompt.skip_hint", &[("keys", &settings.key_bindings.keys_text(GameAction::Cancel))])),
+            TextFont { font_size: 16.0, ..default() },
+            TextColor(Color::srgb(0.7, 0.7, 0.7)),
+        ));
+        spawn_localized_button(parent, &locale, "prompt.cancel", CancelEffectButton);
+    });
+}
+
+pub fn handle_cancel_button(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<CancelEffectButton>)>,
+    mut cancel: MessageWriter<CancelSpecialEffect>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Pressed {
+            cancel.write(CancelSpecialEffect);
+        }
+    }
+}
+
+// glow behind each card that can be picked
+pub fn highlight_targets(
+    mut commands: Commands,
+    special_effect: Option<Res<SpecialCardEffect>>,
+    turn: Res<Turn>,
+    card_query: Query<(Entity, &Card, &Transform), Without<TargetHighlight>>,
+    mut highlight_query: Query<(Entity, &TargetHighlight, &mut Transform)>,
+) {
+    let targets: Vec<(Entity, Transform)> = match special_effect.as_deref().filter(|effect| effect.is_targeting()) {
+        Some(effect) => card_query.iter()
+            .filter(|(_, card, _)| effect.is_valid_target(card, turn.current_player))
+            .map(|(entity, _, transform)| (entity, *transform))
+            .collect(),
+        None => Vec::new(),
+    };
+
+    // follow the card or remove the glow
+    for (entity, highlight, mut transform) in highlight_query.iter_mut() {
+        match targets.iter().find(|(card, _)| *card == highlight.0) {
+            Some((_, card_transform)) => {
+                transform.translation = card_transform.translation - Vec3::Z * 0.5;
+                transform.rotation = card_transform.rotation;
+                transform.scale = card_transform.scale;
+            }
+            None => commands.entity(entity).despawn(),
+        }
+    }
+
+    for (card, card_transform) in targets.iter() {
+        if highlight_query.iter().any(|(_, highlight, _)| highlight.0 == *card) {
+            continue;
+        }
+        commands.spawn((
+            Sprite::from_color(TARGET_COLOR, CARD_SIZE + Vec2::splat(12.0)),
+            Transform {
+                translation: card_transform.translation - Vec3::Z * 0.5,
+                ..*card_transform
+            },
+            Pickable::IGNORE, // behind the card, must not take its clicks
+            TargetHighlight(*card),
+            GameEntity,
+        ));
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn clean_effect_prompt(
+    mut commands: Commands,
+    prompt_query: Query<Entity, Or<(With<EffectPromptUI>, With<TargetHighlight>)>>,
+) {
+    for entity in prompt_query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+use crate::game::special_cards::resource::SpecialEffect;
+
+// visual of a special effect, written by the game when the effect happens
+#[derive(Message, Clone, Copy, Debug)]
+pub enum EffectVfx {
+    Activated(Entity, SpecialEffect), // special card, flourish of particles
+    Reveal(Entity),                   // revealed card glows
+    Shuffle(Vec3),                    // center of the shuffled hand
+    Swap(Vec3, Vec3),                 // places of the exchanged cards, arc trail
+}
+
+// sprite of a visual effect, despawned when its tween ends
+#[derive(Component)]
+pub struct EffectParticle;
+
+pub const FLOURISH_PARTICLES: usize = 14;
+pub const FLOURISH_TIME: f32 = 0.6;
+pub const TRAIL_DOTS: usize = 14;
+pub const TRAIL_FADE_TIME: f32 = 0.5;
+pub const SPIRAL_PARTICLES: usize = 10;
+pub const SPIRAL_STEP_TIME: f32 = 0.1;
+pub const PARTICLE_SIZE: f32 = 7.0;
+
+// over the cards, under the flying ones
+pub const VFX_Z: f32 = 90.0;
+
+pub fn effect_color(effect: SpecialEffect) -> Color {
+    match effect {
+        SpecialEffect::Reveal => Color::srgb(1.0, 0.85, 0.3),
+        SpecialEffect::Shuffle => Color::srgb(0.7, 0.45, 1.0),
+        SpecialEffect::Swap => Color::srgb(0.3, 0.85, 1.0),
+    }
+}
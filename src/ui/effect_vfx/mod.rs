@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+use crate::ui::effect_vfx::component::EffectVfx;
+use crate::ui::effect_vfx::system::{spawn_effect_vfx, despawn_finished_vfx};
+use crate::ui::tween::TweenSet;
+
+pub mod component;
+mod system;
+
+pub struct EffectVfxPlugin;
+
+impl Plugin for EffectVfxPlugin {
+    fn build(&self, app: &mut App) {
+        app
+        .add_message::<EffectVfx>()
+        .add_systems(Update, (
+            spawn_effect_vfx.before(TweenSet),
+            despawn_finished_vfx.after(TweenSet),
+        ));
+    }
+}
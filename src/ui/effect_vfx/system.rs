@@ -0,0 +1,141 @@
+use std::f32::consts::{FRAC_PI_4, TAU};
+use bevy::prelude::*;
+use crate::game::card::component::CARD_SIZE;
+use crate::game::gamestate::GameEntity;
+use crate::game::special_cards::resource::SpecialEffect;
+use crate::game::table::resource::TableLayout;
+use crate::global::settings::resource::Settings;
+use crate::ui::card_animation::component::{arc_point, SWAP_BEND, SWAP_TIME};
+use crate::ui::effect_vfx::component::{
+    EffectVfx, EffectParticle, effect_color, FLOURISH_PARTICLES, FLOURISH_TIME, TRAIL_DOTS, TRAIL_FADE_TIME,
+    SPIRAL_PARTICLES, SPIRAL_STEP_TIME, PARTICLE_SIZE, VFX_Z,
+};
+use crate::ui::tween::component::{Animator, Ease, Tween, TweenCompleted, TweenKind, Vfx};
+
+// small diamond, hidden until its tween shows it
+fn particle(color: Color, position: Vec3, tween: Tween) -> impl Bundle {
+    (
+        Sprite::from_color(color.with_alpha(0.0), Vec2::splat(PARTICLE_SIZE)),
+        Transform::from_translation(position).with_rotation(Quat::from_rotation_z(FRAC_PI_4)),
+        Pickable::IGNORE,
+        Animator::<Vfx>::new(TweenKind::Effect, tween),
+        EffectParticle,
+        GameEntity,
+    )
+}
+
+pub fn spawn_effect_vfx(
+    mut commands: Commands,
+    mut requests: MessageReader<EffectVfx>,
+    card_query: Query<(&Transform, &Sprite)>,
+    layout: Res<TableLayout>,
+    settings: Res<Settings>,
+) {
+    let zoom = layout.card_scale().x;
+
+    for request in requests.read() {
+        match *request {
+            // burst of particles around the special card
+            EffectVfx::Activated(card_entity, effect) => {
+                if settings.reduced_motion {
+                    continue;
+                }
+                let Ok((transform, _)) = card_query.get(card_entity) else { continue; };
+                let center = transform.translation.with_z(VFX_Z);
+                for i in 0..FLOURISH_PARTICLES {
+                    let angle = i as f32 / FLOURISH_PARTICLES as f32 * TAU + rand::random::<f32>() * 0.3;
+                    let distance = (60.0 + rand::random::<f32>() * 50.0) * zoom;
+                    let end = center + Vec3::new(angle.cos(), angle.sin(), 0.0) * distance;
+                    commands.spawn(particle(effect_color(effect), center, Tween::parallel([
+                        Tween::translation(center, end, FLOURISH_TIME).ease(Ease::CubicOut),
+                        Tween::scale(Vec3::splat(1.5), Vec3::splat(0.3), FLOURISH_TIME).ease(Ease::QuadIn),
+                        Tween::alpha(1.0, 0.0, FLOURISH_TIME).ease(Ease::QuadIn),
+                    ])));
+                }
+            }
+
+            // pulsing light behind the revealed card
+            EffectVfx::Reveal(card_entity) => {
+                let Ok((_, sprite)) = card_query.get(card_entity) else { continue; };
+                let size = sprite.custom_size.unwrap_or(CARD_SIZE) * 1.15;
+                let glow = if settings.reduced_motion {
+                    Tween::sequence([Tween::alpha(0.7, 0.7, 1.4), Tween::alpha(0.7, 0.0, 0.0)])
+                } else {
+                    Tween::sequence([
+                        Tween::alpha(0.0, 0.9, 0.2).ease(Ease::QuadOut),
+                        Tween::alpha(0.9, 0.35, 0.35).ease(Ease::QuadInOut),
+                        Tween::alpha(0.35, 0.9, 0.35).ease(Ease::QuadInOut),
+                        Tween::alpha(0.9, 0.0, 0.5).ease(Ease::QuadIn),
+                    ])
+                };
+                commands.entity(card_entity).with_child((
+                    Sprite::from_color(effect_color(SpecialEffect::Reveal).with_alpha(0.0), size),
+                    Transform::from_xyz(0.0, 0.0, -0.5),
+                    Pickable::IGNORE,
+                    Animator::<Vfx>::new(TweenKind::Effect, glow),
+                    EffectParticle,
+                ));
+            }
+
+            // particles spiral into the hand while it is shuffled
+            EffectVfx::Shuffle(center) => {
+                if settings.reduced_motion {
+                    continue;
+                }
+                let center = center.with_z(VFX_Z);
+                let radius = CARD_SIZE.x * zoom * 1.5;
+                let steps = 8;
+                for i in 0..SPIRAL_PARTICLES {
+                    let start = i as f32 / SPIRAL_PARTICLES as f32 * TAU;
+                    // a turn and a quarter, closing to the center
+                    let point = |k: usize| {
+                        let t = k as f32 / steps as f32;
+                        let angle = start + t * TAU * 1.25;
+                        center + Vec3::new(angle.cos(), angle.sin(), 0.0) * radius * (1.0 - t * 0.8)
+                    };
+                    let mut path: Vec<Tween> = (0..steps)
+                        .map(|k| Tween::translation(point(k), point(k + 1), SPIRAL_STEP_TIME))
+                        .collect();
+                    path.insert(0, Tween::alpha(0.0, 1.0, 0.0));
+                    let duration = steps as f32 * SPIRAL_STEP_TIME;
+                    commands.spawn(particle(effect_color(SpecialEffect::Shuffle), point(0), Tween::parallel([
+                        Tween::sequence(path),
+                        Tween::sequence([Tween::delay(duration * 0.6), Tween::alpha(1.0, 0.0, duration * 0.4)]),
+                    ])));
+                }
+            }
+
+            // dots along both arcs, lit while the cards go through and fading behind them
+            EffectVfx::Swap(from, to) => {
+                if settings.reduced_motion {
+                    continue;
+                }
+                for (start, end) in [(from, to), (to, from)] {
+                    for i in 1..TRAIL_DOTS {
+                        let t = i as f32 / TRAIL_DOTS as f32;
+                        let position = arc_point(start, end, SWAP_BEND, t).with_z(VFX_Z);
+                        commands.spawn(particle(effect_color(SpecialEffect::Swap), position, Tween::sequence([
+                            Tween::delay(t * SWAP_TIME),
+                            Tween::parallel([
+                                Tween::alpha(0.9, 0.0, TRAIL_FADE_TIME).ease(Ease::QuadIn),
+                                Tween::scale(Vec3::ONE, Vec3::splat(0.3), TRAIL_FADE_TIME),
+                            ]),
+                        ])));
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn despawn_finished_vfx(
+    mut commands: Commands,
+    mut completed: MessageReader<TweenCompleted>,
+    particle_query: Query<(), With<EffectParticle>>,
+) {
+    for event in completed.read().filter(|event| event.kind == TweenKind::Effect) {
+        if particle_query.get(event.entity).is_ok() {
+            commands.entity(event.entity).despawn();
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+
+// button that can be reached with keyboard/gamepad navigation
+#[derive(Component)]
+pub struct Focusable;
+
+// focused button and whether an overlay (z index > 0) owns the navigation
+#[derive(Resource, Default)]
+pub struct FocusState {
+    pub entity: Option<Entity>,
+    pub overlay_active: bool,
+}
+
+// confirm pressed on a focused sprite button
+#[derive(Message)]
+pub struct FocusActivated(pub Entity);
+
+// color of the focused sprite/border
+pub const FOCUS_COLOR: Color = Color::srgb(1.0, 0.85, 0.3);
@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+use bevy::ui::UiSystems;
+use crate::ui::focus::component::{FocusState, FocusActivated};
+use crate::ui::focus::system::{navigate_focus, activate_focus, highlight_focus};
+
+pub mod component;
+pub mod system;
+
+pub struct FocusPlugin;
+
+impl Plugin for FocusPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<FocusState>()
+            .add_message::<FocusActivated>()
+            // after bevy ui updates Interaction, so the button handlers see the press this frame
+            .add_systems(PreUpdate, (navigate_focus, activate_focus).chain().after(UiSystems::Focus))
+            .add_systems(Update, highlight_focus);
+    }
+}
@@ -0,0 +1,152 @@
+use bevy::prelude::*;
+use crate::game::gamestate::AppState;
+use crate::global::input::{resource::GameAction, system::ActionInput};
+use crate::ui::focus::component::{Focusable, FocusState, FocusActivated, FOCUS_COLOR};
+use crate::ui::widget::component::{TextButton, BUTTON_BORDER};
+
+// screen position (logical px, y down) and layer of each focusable
+#[allow(clippy::type_complexity)]
+fn collect_focusables(
+    focusable_query: &Query<(Entity, Option<&UiGlobalTransform>, Option<&ComputedNode>, Option<&GlobalTransform>, Option<&InheritedVisibility>), With<Focusable>>,
+    parent_query: &Query<&ChildOf>,
+    z_query: &Query<&GlobalZIndex>,
+    camera_query: &Query<(&Camera, &GlobalTransform)>,
+) -> Vec<(Entity, Vec2, i32)> {
+    let camera = camera_query.single().ok();
+
+    focusable_query.iter().filter_map(|(entity, ui_transform, node, transform, visibility)| {
+        if visibility.is_some_and(|v| !v.get()) {
+            return None;
+        }
+
+        // layer = z index of the root ui node (sprites are always 0)
+        let mut layer = 0;
+        let mut current = entity;
+        loop {
+            if let Ok(z) = z_query.get(current) {
+                layer = z.0;
+            }
+            match parent_query.get(current) {
+                Ok(child_of) => current = child_of.parent(),
+                Err(_) => break,
+            }
+        }
+
+        let position = match (ui_transform, node, transform) {
+            (Some(ui_transform), Some(node), _) => ui_transform.translation * node.inverse_scale_factor(),
+            (_, _, Some(transform)) => {
+                let (camera, camera_transform) = camera?;
+                camera.world_to_viewport(camera_transform, transform.translation()).ok()?
+            }
+            _ => return None,
+        };
+
+        Some((entity, position, layer))
+    }).collect()
+}
+
+// move the focus with arrows/d-pad to the nearest button in that direction
+#[allow(clippy::type_complexity)]
+pub fn navigate_focus(
+    input: ActionInput,
+    mut focus: ResMut<FocusState>,
+    focusable_query: Query<(Entity, Option<&UiGlobalTransform>, Option<&ComputedNode>, Option<&GlobalTransform>, Option<&InheritedVisibility>), With<Focusable>>,
+    parent_query: Query<&ChildOf>,
+    z_query: Query<&GlobalZIndex>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+    state: Res<State<AppState>>,
+) {
+    let focusables = collect_focusables(&focusable_query, &parent_query, &z_query, &camera_query);
+
+    // only the top layer can be navigated
+    let top_layer = focusables.iter().map(|(_, _, layer)| *layer).max().unwrap_or(0);
+    focus.overlay_active = top_layer > 0;
+
+    // during the turn the board navigation owns the arrows (it includes the back button)
+    if *state.get() == AppState::PlayerTurn && !focus.overlay_active {
+        return;
+    }
+
+    let candidates: Vec<(Entity, Vec2)> = focusables.into_iter()
+        .filter(|(_, _, layer)| *layer == top_layer)
+        .map(|(entity, position, _)| (entity, position))
+        .collect();
+
+    // lost focus (despawned or other layer)
+    let current = focus.entity.and_then(|e| candidates.iter().find(|(c, _)| *c == e).copied());
+    if current.is_none() {
+        focus.entity = None;
+    }
+
+    let Some(direction) = input.navigation() else { return; };
+
+    // first press focuses the top-left button
+    let Some((current_entity, current_pos)) = current else {
+        focus.entity = candidates.iter()
+            .min_by(|(_, a), (_, b)| (a.y, a.x).partial_cmp(&(b.y, b.x)).unwrap())
+            .map(|(e, _)| *e);
+        return;
+    };
+
+    // screen y goes down
+    let dir = Vec2::new(direction.x as f32, -direction.y as f32);
+    let next = candidates.iter()
+        .filter(|(e, _)| *e != current_entity)
+        .filter_map(|(e, pos)| {
+            let delta = *pos - current_pos;
+            let forward = delta.dot(dir);
+            if forward <= 1.0 {
+                return None;
+            }
+            // prefer buttons aligned with the direction
+            let side = (delta - dir * forward).length();
+            Some((*e, forward + side * 2.0))
+        })
+        .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+        .map(|(e, _)| e);
+
+    if next.is_some() {
+        focus.entity = next;
+    }
+}
+
+// confirm on the focused button
+pub fn activate_focus(
+    input: ActionInput,
+    focus: Res<FocusState>,
+    mut interaction_query: Query<&mut Interaction>,
+    mut activated: MessageWriter<FocusActivated>,
+) {
+    if !input.just_pressed(GameAction::Confirm) {
+        return;
+    }
+    let Some(entity) = focus.entity else { return; };
+
+    // bevy ui buttons react to the pressed interaction, sprite buttons to the message
+    if let Ok(mut interaction) = interaction_query.get_mut(entity) {
+        *interaction = Interaction::Pressed;
+    } else {
+        activated.write(FocusActivated(entity));
+    }
+}
+
+// border on focused ui buttons, tint on focused sprite buttons
+#[allow(clippy::type_complexity)]
+pub fn highlight_focus(
+    focus: Res<FocusState>,
+    mut border_query: Query<(Entity, &mut BorderColor), With<TextButton>>,
+    mut sprite_query: Query<(Entity, &mut Sprite), (With<Focusable>, Without<TextButton>)>,
+) {
+    for (entity, mut border) in border_query.iter_mut() {
+        let color = if focus.entity == Some(entity) { FOCUS_COLOR } else { BUTTON_BORDER };
+        if border.top != color {
+            border.set_all(color);
+        }
+    }
+    for (entity, mut sprite) in sprite_query.iter_mut() {
+        let color = if focus.entity == Some(entity) { FOCUS_COLOR } else { Color::WHITE };
+        if sprite.color != color {
+            sprite.color = color;
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use bevy::prelude::*;
+
+// full screen handoff overlay
+#[derive(Component)]
+pub struct HandoffUI;
+
+// takes the seat, only a press made on the overlay counts
+#[derive(Component)]
+pub struct HandoffConfirmButton;
@@ -0,0 +1,18 @@
+use bevy::prelude::*;
+use crate::game::gamestate::{AppState, PauseState};
+use crate::game::card::system::card_selection;
+use crate::ui::handoff::system::{update_handoff_screen, confirm_handoff, clean_handoff_screen};
+
+pub mod component;
+mod system;
+
+pub struct HandoffPlugin;
+
+impl Plugin for HandoffPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            // after card_selection, so the confirm press does not reach the cards
+            .add_systems(Update, (update_handoff_screen, confirm_handoff).chain().after(card_selection).run_if(in_state(AppState::PlayerTurn)).run_if(in_state(PauseState::Running)))
+            .add_systems(OnExit(AppState::PlayerTurn), clean_handoff_screen);
+    }
+}
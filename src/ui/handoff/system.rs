@@ -0,0 +1,97 @@
+use bevy::prelude::*;
+use crate::ui::handoff::component::{HandoffUI, HandoffConfirmButton};
+use crate::ui::widget::system::spawn_localized_button;
+use crate::global::input::{resource::GameAction, system::ActionInput};
+use crate::global::settings::resource::Settings;
+use crate::global::locale::resource::Locale;
+use crate::game::{player::component::Player, turn_player::component::{Turn, HotSeat}, gamestate::GameEntity};
+
+// spawn the "pass to player" screen when the turn changes, despawn it when confirmed
+pub fn update_handoff_screen(
+    mut commands: Commands,
+    hot_seat: Res<HotSeat>,
+    turn: Option<Res<Turn>>,
+    player_query: Query<&Player>,
+    handoff_query: Query<Entity, With<HandoffUI>>,
+    settings: Res<Settings>,
+    locale: Res<Locale>,
+) {
+    let Some(turn) = turn else { return; };
+    let pending = hot_seat.is_pending(&turn);
+
+    if !pending {
+        for entity in handoff_query.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+
+    // spawn if not exists now
+    if !handoff_query.is_empty() {
+        return;
+    }
+
+    let player_name = player_query.get(turn.current_player)
+        .map(|player| player.name.clone())
+        .unwrap_or_default();
+
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            position_type: PositionType::Absolute,
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(20.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.05, 0.05, 0.08)),
+        GlobalZIndex(100),
+        HandoffUI,
+        GameEntity,
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new(locale.format("handoff.pass_to", &[("name", &player_name)])),
+            TextFont { font_size: 48.0, ..default() },
+            TextColor(Color::WHITE),
+        ));
+        parent.spawn((
+            Text::new(locale.format("handoff.ready", &[("keys", &settings.key_bindings.keys_text(GameAction::Confirm))])),
+            TextFont { font_size: 22.0, ..default() },
+            TextColor(Color::srgb(0.7, 0.7, 0.7)),
+        ));
+        spawn_localized_button(parent, &locale, "handoff.confirm", HandoffConfirmButton);
+    });
+    info!(target: "mygame", "Waiting for {} to take the seat", player_name);
+}
+
+// confirm the handoff with the button or the confirm keys
+pub fn confirm_handoff(
+    button_query: Query<&Interaction, (Changed<Interaction>, With<HandoffConfirmButton>)>,
+    input: ActionInput,
+    mut hot_seat: ResMut<HotSeat>,
+    turn: Option<Res<Turn>>,
+) {
+    let Some(turn) = turn else { return; };
+    if !hot_seat.is_pending(&turn) {
+        return;
+    }
+
+    // the click that ended the last turn was pressed before the button existed, it does not count
+    let pressed = button_query.iter().any(|interaction| *interaction == Interaction::Pressed);
+    if pressed || input.just_pressed(GameAction::Confirm) {
+        hot_seat.confirmed_player = Some(turn.current_player);
+        info!(target: "mygame", "Handoff confirmed by player: {:?}", turn.current_player);
+    }
+}
+
+// remove overlay when leaving the turn state
+pub fn clean_handoff_screen(
+    mut commands: Commands,
+    handoff_query: Query<Entity, With<HandoffUI>>,
+) {
+    for entity in handoff_query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
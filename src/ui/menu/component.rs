@@ -16,6 +16,14 @@ pub struct PlayButton;
 #[derive(Component)]
 pub struct ExitButton;
 
+// stats button (bevy ui)
+#[derive(Component)]
+pub struct StatsButton;
+
+// settings button (bevy ui)
+#[derive(Component)]
+pub struct SettingsButton;
+
 // button images
 #[derive(Component)]
 pub struct ButtonImages {
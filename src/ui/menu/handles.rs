@@ -1,10 +1,12 @@
 use bevy::prelude::*;
 
 use bevy::window::PrimaryWindow;
-use crate::ui::menu::component::{ButtonState, ButtonImages, ExitButton, PlayButton, MainMenuUI};
-use crate::game::gamestate::AppState;
+use crate::ui::menu::component::{ButtonState, ButtonImages, ExitButton, PlayButton, MainMenuUI, StatsButton, SettingsButton};
+use crate::game::gamestate::{AppState, MenuPage, SettingsState};
+use crate::ui::focus::component::FocusActivated;
 
 // detect click in PLAY and change to Setup, detect click in EXIT and close game
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn handle_button_clicks(
     mouse_input: Res<ButtonInput<MouseButton>>,
     windows: Query<&Window, With<PrimaryWindow>>,
@@ -14,7 +16,20 @@ pub fn handle_button_clicks(
     sprites: Query<&Sprite>,
     mut next_state: ResMut<NextState<AppState>>,
     mut exit_query: MessageWriter<AppExit>,
+    mut activated: MessageReader<FocusActivated>,
 ) {
+    // keyboard/gamepad confirm on a focused button
+    for FocusActivated(entity) in activated.read() {
+        if let Ok((_, _, play, exit, _)) = button_query.get(*entity) {
+            if play.is_some() {
+                next_state.set(AppState::Setup);
+                info!(target: "mygame", "Starting game...");
+            } else if exit.is_some() {
+                exit_query.write(AppExit::Success);
+            }
+        }
+    }
+
     // only if click left mouse button
     if mouse_input.just_pressed(MouseButton::Left) {
         // obtain window, camera, cursor/world position
@@ -137,4 +152,24 @@ pub fn update_button_hover(
             *state = ButtonState::Normal;
         }
     }
+}
+
+// detect click in the bevy ui menu options
+#[allow(clippy::type_complexity)]
+pub fn handle_menu_options(
+    interaction_query: Query<(&Interaction, Option<&StatsButton>, Option<&SettingsButton>), Changed<Interaction>>,
+    mut next_page: ResMut<NextState<MenuPage>>,
+    mut next_settings: ResMut<NextState<SettingsState>>,
+) {
+    for (interaction, stats, settings) in interaction_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        if stats.is_some() {
+            next_page.set(MenuPage::Stats);
+        } else if settings.is_some() {
+            next_settings.set(SettingsState::Open);
+        }
+    }
 }
\ No newline at end of file
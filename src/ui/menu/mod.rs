@@ -1,20 +1,21 @@
 use bevy::prelude::*;
-use crate::game::gamestate::AppState;
+use crate::game::gamestate::{AppState, MenuPage, SettingsState};
 
 pub mod component;
 mod system;
 mod handles;
 
-use crate::ui::menu::system::{spawn_background, spawn_logo, spawn_buttons, adjust_background, clean_menu, initial_adjust_background};
-use crate::ui::menu::handles::{handle_button_clicks, update_button_visuals, update_button_hover};
+use crate::ui::menu::system::{spawn_background, spawn_logo, spawn_buttons, spawn_menu_options, adjust_background, clean_menu, initial_adjust_background};
+use crate::ui::menu::handles::{handle_button_clicks, update_button_visuals, update_button_hover, handle_menu_options};
 pub struct MenuPlugin;
 
 impl Plugin for MenuPlugin {
     fn build(&self, app: &mut App) {
         app
-        .add_systems(OnEnter(AppState::MainMenu), (spawn_background, spawn_logo, spawn_buttons))
+        .add_systems(OnEnter(AppState::MainMenu), (spawn_background, spawn_logo, spawn_buttons, spawn_menu_options))
         .add_systems(Update, initial_adjust_background.run_if(in_state(AppState::MainMenu)))
-        .add_systems(Update, (adjust_background, update_button_hover, handle_button_clicks, update_button_visuals).run_if(in_state(AppState::MainMenu)))
+        .add_systems(Update, adjust_background.run_if(in_state(AppState::MainMenu)))
+        .add_systems(Update, (update_button_hover, handle_button_clicks, update_button_visuals, handle_menu_options).run_if(in_state(MenuPage::Main)).run_if(in_state(SettingsState::Closed)))
         .add_systems(OnExit(AppState::MainMenu), clean_menu);
     }
 }
\ No newline at end of file
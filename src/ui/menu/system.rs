@@ -1,6 +1,9 @@
 use bevy::prelude::*;
 
-use crate::{ui::menu::component::{ButtonImages, ButtonState, ExitButton, MainMenuUI, MenuBackground, PlayButton}};
+use crate::{ui::menu::component::{ButtonImages, ButtonState, ExitButton, MainMenuUI, MenuBackground, PlayButton, StatsButton, SettingsButton}};
+use crate::ui::widget::system::spawn_localized_button;
+use crate::global::locale::resource::Locale;
+use crate::ui::focus::component::Focusable;
 use bevy::window::{PrimaryWindow, WindowResized};
 
 // spawn_background
@@ -112,6 +115,7 @@ pub fn spawn_buttons(
         ButtonImages {normal: image_play_normal, pressed: image_play_pressed, hovered: image_play_hover},
         ButtonState::Normal,
         PlayButton,
+        Focusable,
         MainMenuUI,
     ));
 
@@ -121,10 +125,29 @@ pub fn spawn_buttons(
         ButtonImages {normal: image_exit_normal, pressed: image_exit_pressed, hovered: image_exit_hover},
         ButtonState::Normal,
         ExitButton,
+        Focusable,
         MainMenuUI,
     ));
 }
 
+// spawn secondary buttons (bevy ui) under the play/exit buttons
+pub fn spawn_menu_options(mut commands: Commands, locale: Res<Locale>) {
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            position_type: PositionType::Absolute,
+            bottom: Val::Percent(18.0),
+            justify_content: JustifyContent::Center,
+            column_gap: Val::Px(16.0),
+            ..default()
+        },
+        MainMenuUI,
+    )).with_children(|parent| {
+        spawn_localized_button(parent, &locale, "menu.stats", StatsButton);
+        spawn_localized_button(parent, &locale, "menu.settings", SettingsButton);
+    });
+}
+
 // clean_menu
 pub fn clean_menu(
     mut commands: Commands,
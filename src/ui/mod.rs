@@ -6,6 +6,21 @@ pub mod back_button;
 pub mod turn_indicator;
 pub mod card_animation;
 pub mod soundtrack;
+pub mod handoff;
+pub mod widget;
+pub mod stats_menu;
+pub mod settings_menu;
+pub mod focus;
+pub mod board_focus;
+pub mod results;
+pub mod effect_prompt;
+pub mod toast;
+pub mod pause_menu;
+pub mod tween;
+pub mod deck_shuffle;
+pub mod effect_vfx;
+pub mod pile_counter;
+pub mod discard_history;
 
 use background_game::BackgroundPlugin;
 use menu::MenuPlugin;
@@ -13,6 +28,21 @@ use back_button::BackButtonPlugin;
 use turn_indicator::TurnIndicatorPlugin;
 use card_animation::CardAnimationPlugin;
 use soundtrack::AudioPlugin;
+use handoff::HandoffPlugin;
+use widget::WidgetPlugin;
+use stats_menu::StatsMenuPlugin;
+use settings_menu::SettingsMenuPlugin;
+use focus::FocusPlugin;
+use board_focus::BoardFocusPlugin;
+use results::ResultsPlugin;
+use effect_prompt::EffectPromptPlugin;
+use toast::ToastPlugin;
+use pause_menu::PauseMenuPlugin;
+use tween::TweenPlugin;
+use deck_shuffle::DeckShufflePlugin;
+use effect_vfx::EffectVfxPlugin;
+use pile_counter::PileCounterPlugin;
+use discard_history::DiscardHistoryPlugin;
 
 pub struct UiPlugin;
 
@@ -23,7 +53,22 @@ impl Plugin for UiPlugin {
         .add_plugins(MenuPlugin)
         .add_plugins(BackButtonPlugin)
         .add_plugins(TurnIndicatorPlugin)
+        .add_plugins(TweenPlugin)
         .add_plugins(CardAnimationPlugin)
-        .add_plugins(AudioPlugin);
+        .add_plugins(DeckShufflePlugin)
+        .add_plugins(EffectVfxPlugin)
+        .add_plugins(PileCounterPlugin)
+        .add_plugins(DiscardHistoryPlugin)
+        .add_plugins(AudioPlugin)
+        .add_plugins(HandoffPlugin)
+        .add_plugins(WidgetPlugin)
+        .add_plugins(StatsMenuPlugin)
+        .add_plugins(SettingsMenuPlugin)
+        .add_plugins(FocusPlugin)
+        .add_plugins(BoardFocusPlugin)
+        .add_plugins(ResultsPlugin)
+        .add_plugins(EffectPromptPlugin)
+        .add_plugins(ToastPlugin)
+        .add_plugins(PauseMenuPlugin);
     }
 }
\ No newline at end of file
@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+
+// pause overlay root
+#[derive(Component)]
+pub struct PauseUI;
+
+// container rebuilt when the page changes
+#[derive(Component)]
+pub struct PauseContent;
+
+#[derive(Component)]
+pub struct ResumeButton;
+
+#[derive(Component)]
+pub struct PauseSettingsButton;
+
+#[derive(Component)]
+pub struct RulesButton;
+
+#[derive(Component)]
+pub struct RestartRoundButton;
+
+#[derive(Component)]
+pub struct QuitButton;
+
+#[derive(Component)]
+pub struct ConfirmQuitButton;
+
+// back to the main page (rules or quit confirmation)
+#[derive(Component)]
+pub struct PauseBackButton;
+
+#[derive(Clone, Copy, PartialEq, Default)]
+pub enum PausePage {
+    #[default]
+    Main,
+    Rules,
+    ConfirmQuit,
+}
+
+#[derive(Resource, Default)]
+pub struct PauseScreen {
+    pub page: PausePage,
+}
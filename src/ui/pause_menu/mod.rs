@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+use crate::game::gamestate::PauseState;
+use crate::ui::pause_menu::component::PauseScreen;
+use crate::ui::pause_menu::system::{toggle_pause, spawn_pause_menu, clean_pause_menu, build_pause_content, handle_pause_buttons, freeze_time, resume_time, leave_pause};
+use crate::game::gamestate::AppState;
+
+pub mod component;
+mod system;
+
+pub struct PauseMenuPlugin;
+
+impl Plugin for PauseMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<PauseScreen>()
+            .add_systems(Update, toggle_pause)
+            .add_systems(OnEnter(PauseState::Paused), (freeze_time, spawn_pause_menu))
+            .add_systems(OnExit(PauseState::Paused), (resume_time, clean_pause_menu))
+            .add_systems(Update, (build_pause_content, handle_pause_buttons).chain().run_if(in_state(PauseState::Paused)))
+            // leaving the game always unpauses
+            .add_systems(OnEnter(AppState::MainMenu), leave_pause);
+    }
+}
@@ -0,0 +1,201 @@
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+use crate::game::gamestate::{AppState, PauseState, SettingsState};
+use crate::game::round_end::resource::NewRoundRequested;
+use crate::global::input::{resource::GameAction, system::ActionInput};
+use crate::ui::pause_menu::component::{
+    PauseUI, PauseContent, ResumeButton, PauseSettingsButton, RulesButton, RestartRoundButton,
+    QuitButton, ConfirmQuitButton, PauseBackButton, PausePage, PauseScreen,
+};
+use crate::global::locale::resource::{Locale, LocalizedText};
+use crate::ui::widget::system::spawn_localized_button;
+
+// pause key opens/closes the menu during a game, inner pages go back first
+pub fn toggle_pause(
+    input: ActionInput,
+    app_state: Res<State<AppState>>,
+    pause_state: Res<State<PauseState>>,
+    settings_state: Res<State<SettingsState>>,
+    mut next_pause: ResMut<NextState<PauseState>>,
+    mut screen: ResMut<PauseScreen>,
+) {
+    if *app_state.get() == AppState::MainMenu || *settings_state.get() == SettingsState::Open {
+        return;
+    }
+    if !input.just_pressed(GameAction::Pause) {
+        return;
+    }
+
+    match pause_state.get() {
+        PauseState::Running => next_pause.set(PauseState::Paused),
+        PauseState::Paused if screen.page != PausePage::Main => screen.page = PausePage::Main,
+        PauseState::Paused => next_pause.set(PauseState::Running),
+    }
+}
+
+// stop the virtual clock: animations, timers and effects freeze
+pub fn freeze_time(mut time: ResMut<Time<Virtual>>) {
+    time.pause();
+    info!(target: "mygame", "Game paused");
+}
+
+pub fn resume_time(mut time: ResMut<Time<Virtual>>) {
+    time.unpause();
+    info!(target: "mygame", "Game resumed");
+}
+
+pub fn leave_pause(mut next_pause: ResMut<NextState<PauseState>>) {
+    next_pause.set(PauseState::Running);
+}
+
+pub fn spawn_pause_menu(
+    mut commands: Commands,
+    mut screen: ResMut<PauseScreen>,
+    locale: Res<Locale>,
+) {
+    // keep a page preset by whoever paused (the back button opens ConfirmQuit)
+    screen.set_changed();
+
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            position_type: PositionType::Absolute,
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(16.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.8)),
+        FocusPolicy::Block,
+        GlobalZIndex(120),
+        PauseUI,
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new(locale.get("pause.title")),
+            TextFont { font_size: 48.0, ..default() },
+            TextColor(Color::WHITE),
+            LocalizedText("pause.title"),
+        ));
+
+        // buttons are built by build_pause_content
+        parent.spawn((
+            Node {
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(10.0),
+                ..default()
+            },
+            PauseContent,
+        ));
+    });
+}
+
+pub fn clean_pause_menu(
+    mut commands: Commands,
+    mut screen: ResMut<PauseScreen>,
+    pause_query: Query<Entity, With<PauseUI>>,
+) {
+    *screen = PauseScreen::default();
+    for entity in pause_query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+pub fn build_pause_content(
+    mut commands: Commands,
+    screen: Res<PauseScreen>,
+    locale: Res<Locale>,
+    content_query: Query<Entity, With<PauseContent>>,
+) {
+    if !screen.is_changed() {
+        return;
+    }
+
+    let Ok(content) = content_query.single() else { return; };
+    commands.entity(content).despawn_related::<Children>();
+
+    commands.entity(content).with_children(|parent| {
+        match screen.page {
+            PausePage::Main => {
+                spawn_localized_button(parent, &locale, "pause.resume", ResumeButton);
+                spawn_localized_button(parent, &locale, "pause.settings", PauseSettingsButton);
+                spawn_localized_button(parent, &locale, "pause.rules", RulesButton);
+                spawn_localized_button(parent, &locale, "pause.restart", RestartRoundButton);
+                spawn_localized_button(parent, &locale, "pause.quit", QuitButton);
+            }
+            PausePage::Rules => {
+                parent.spawn((
+                    Text::new(locale.get("pause.rules_text")),
+                    TextFont { font_size: 20.0, ..default() },
+                    TextColor(Color::srgb(0.9, 0.9, 0.9)),
+                    TextLayout::new_with_justify(Justify::Center),
+                    LocalizedText("pause.rules_text"),
+                ));
+                spawn_localized_button(parent, &locale, "pause.back", PauseBackButton);
+            }
+            PausePage::ConfirmQuit => {
+                parent.spawn((
+                    Text::new(locale.get("pause.confirm_quit")),
+                    TextFont { font_size: 24.0, ..default() },
+                    TextColor(Color::WHITE),
+                    LocalizedText("pause.confirm_quit"),
+                ));
+                parent.spawn(Node { column_gap: Val::Px(12.0), ..default() }).with_children(|row| {
+                    spawn_localized_button(row, &locale, "pause.confirm_yes", ConfirmQuitButton);
+                    spawn_localized_button(row, &locale, "pause.confirm_no", PauseBackButton);
+                });
+            }
+        }
+    });
+}
+
+#[allow(clippy::type_complexity)]
+pub fn handle_pause_buttons(
+    interaction_query: Query<(
+        &Interaction,
+        Option<&ResumeButton>,
+        Option<&PauseSettingsButton>,
+        Option<&RulesButton>,
+        Option<&RestartRoundButton>,
+        Option<&QuitButton>,
+        Option<&ConfirmQuitButton>,
+        Option<&PauseBackButton>,
+    ), Changed<Interaction>>,
+    mut screen: ResMut<PauseScreen>,
+    mut next_pause: ResMut<NextState<PauseState>>,
+    mut next_settings: ResMut<NextState<SettingsState>>,
+    mut next_app: ResMut<NextState<AppState>>,
+    app_state: Res<State<AppState>>,
+    mut new_round: MessageWriter<NewRoundRequested>,
+) {
+    for (interaction, resume, settings, rules, restart, quit, confirm_quit, back) in interaction_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        if resume.is_some() {
+            next_pause.set(PauseState::Running);
+        } else if settings.is_some() {
+            next_settings.set(SettingsState::Open);
+        } else if rules.is_some() {
+            screen.page = PausePage::Rules;
+        } else if restart.is_some() {
+            // a round is being dealt in setup, nothing to restart yet
+            if *app_state.get() != AppState::Setup {
+                new_round.write(NewRoundRequested);
+                next_pause.set(PauseState::Running);
+                info!(target: "mygame", "Restarting round...");
+            }
+        } else if quit.is_some() {
+            screen.page = PausePage::ConfirmQuit;
+        } else if confirm_quit.is_some() {
+            next_pause.set(PauseState::Running);
+            next_app.set(AppState::MainMenu);
+            info!(target: "mygame", "Return to menu...");
+        } else if back.is_some() {
+            screen.page = PausePage::Main;
+        }
+    }
+}
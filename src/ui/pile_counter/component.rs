@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+
+// cards left in a pile of the table
+#[derive(Component, Clone, Copy, PartialEq)]
+pub enum PileCounter {
+    Deck,
+    Graveyard,
+}
+
+// the deck counter turns red from here, the round is about to end
+pub const LOW_DECK: usize = 5;
+
+pub const COUNTER_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.8);
+pub const LOW_COUNTER_COLOR: Color = Color::srgb(1.0, 0.4, 0.35);
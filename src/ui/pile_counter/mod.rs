@@ -0,0 +1,16 @@
+use bevy::prelude::*;
+use crate::game::gamestate::AppState;
+use crate::ui::pile_counter::system::{spawn_pile_counters, update_pile_counters};
+
+pub mod component;
+mod system;
+
+pub struct PileCounterPlugin;
+
+impl Plugin for PileCounterPlugin {
+    fn build(&self, app: &mut App) {
+        app
+        .add_systems(OnEnter(AppState::Setup), spawn_pile_counters)
+        .add_systems(Update, update_pile_counters);
+    }
+}
@@ -0,0 +1,53 @@
+use bevy::prelude::*;
+use crate::game::{deck::component::Deck, graveyard::component::Graveyard, gamestate::GameEntity};
+use crate::game::table::resource::TableLayout;
+use crate::global::locale::resource::Locale;
+use crate::ui::pile_counter::component::{PileCounter, LOW_DECK, COUNTER_COLOR, LOW_COUNTER_COLOR};
+
+pub fn spawn_pile_counters(mut commands: Commands, layout: Res<TableLayout>) {
+    for (counter, position) in [
+        (PileCounter::Deck, layout.deck_counter_position()),
+        (PileCounter::Graveyard, layout.graveyard_counter_position()),
+    ] {
+        commands.spawn((
+            Text2d::default(),
+            TextFont { font_size: 16.0, ..default() },
+            TextColor(COUNTER_COLOR),
+            Transform::from_translation(position),
+            Pickable::IGNORE,
+            counter,
+            GameEntity,
+        ));
+    }
+}
+
+// text, color and place of the counters (only written when something changed)
+pub fn update_pile_counters(
+    mut counter_query: Query<(&PileCounter, &mut Text2d, &mut TextColor, &mut Transform)>,
+    deck_query: Query<&Deck>,
+    graveyard_query: Query<&Graveyard>,
+    layout: Res<TableLayout>,
+    locale: Res<Locale>,
+) {
+    let deck_count = deck_query.single().map(|deck| deck.cards_values.len()).unwrap_or(0);
+    let graveyard_count = graveyard_query.single().map(|graveyard| graveyard.cards.len()).unwrap_or(0);
+
+    for (counter, mut text, mut color, mut transform) in counter_query.iter_mut() {
+        let (count, position, low) = match counter {
+            PileCounter::Deck => (deck_count, layout.deck_counter_position(), deck_count <= LOW_DECK),
+            PileCounter::Graveyard => (graveyard_count, layout.graveyard_counter_position(), false),
+        };
+
+        let label = locale.format("pile.count", &[("count", &count)]);
+        if text.0 != label {
+            text.0 = label;
+        }
+        let tint = if low { LOW_COUNTER_COLOR } else { COUNTER_COLOR };
+        if color.0 != tint {
+            color.0 = tint;
+        }
+        if transform.translation != position {
+            transform.translation = position;
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+
+// results panel shown at round end
+#[derive(Component)]
+pub struct ResultsUI;
+
+// row of one player, by reveal order
+#[derive(Component)]
+pub struct ResultsRow(pub usize);
+
+#[derive(Component)]
+pub struct ResultsScoreText(pub usize);
+
+#[derive(Component)]
+pub struct StandingsText;
+
+// container of the buttons, filled when the reveal ends
+#[derive(Component)]
+pub struct ResultsButtons;
+
+#[derive(Component)]
+pub struct NextRoundButton;
+
+#[derive(Component)]
+pub struct ResultsMenuButton;
+
+// progress of the reveal animation
+#[derive(Resource, Default)]
+pub struct ResultsReveal {
+    pub players: Vec<(Entity, i32)>, // reveal order (seat) and hand score
+    pub step: usize, // player being revealed
+    pub timer: f32,
+    pub finished: bool,
+}
+
+// highlight of the winner row
+pub const WINNER_COLOR: Color = Color::srgba(0.75, 0.6, 0.35, 0.45);
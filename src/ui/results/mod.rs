@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+use crate::game::gamestate::AppState;
+use crate::game::round_end::system::update_standings;
+use crate::ui::results::component::ResultsReveal;
+use crate::ui::results::system::{spawn_results_screen, clean_results_screen, animate_results, handle_results_buttons};
+
+pub mod component;
+mod system;
+
+pub struct ResultsPlugin;
+
+impl Plugin for ResultsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<ResultsReveal>()
+            .add_systems(OnEnter(AppState::RoundEnd), spawn_results_screen.after(update_standings))
+            .add_systems(OnExit(AppState::RoundEnd), clean_results_screen)
+            .add_systems(Update, (animate_results, handle_results_buttons).chain().run_if(in_state(AppState::RoundEnd)));
+    }
+}
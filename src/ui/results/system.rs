@@ -0,0 +1,217 @@
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+use crate::game::card::component::Card;
+use crate::game::gamestate::{AppState, GameEntity};
+use crate::game::player::component::Player;
+use crate::game::round_end::resource::{RoundResult, MatchStandings, NewRoundRequested};
+use crate::game::round_end::system::reveal_hand;
+use crate::global::settings::resource::Settings;
+use crate::global::locale::resource::{Locale, LocalizedText};
+use crate::ui::results::component::{ResultsUI, ResultsRow, ResultsScoreText, StandingsText, ResultsButtons, NextRoundButton, ResultsMenuButton, ResultsReveal, WINNER_COLOR};
+use crate::ui::soundtrack::resource::CardSound;
+use crate::ui::widget::component::BUTTON_BORDER;
+use crate::ui::widget::system::spawn_localized_button;
+
+// seconds to flip one hand and to count its score
+const REVEAL_TIME: f32 = 0.6;
+const COUNT_TIME: f32 = 0.8;
+
+pub fn spawn_results_screen(
+    mut commands: Commands,
+    result: Res<RoundResult>,
+    player_query: Query<&Player>,
+    mut reveal: ResMut<ResultsReveal>,
+    locale: Res<Locale>,
+) {
+    // reveal by seat order
+    let mut players: Vec<(Entity, String, i32, usize)> = result.scores.iter()
+        .filter_map(|(entity, name, score)| {
+            player_query.get(*entity).ok().map(|player| (*entity, name.clone(), *score, player.seat))
+        })
+        .collect();
+    players.sort_by_key(|(_, _, _, seat)| *seat);
+
+    *reveal = ResultsReveal {
+        players: players.iter().map(|(entity, _, score, _)| (*entity, *score)).collect(),
+        ..default()
+    };
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Percent(2.0),
+            top: Val::Percent(10.0),
+            width: Val::Percent(26.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Stretch,
+            row_gap: Val::Px(10.0),
+            padding: UiRect::all(Val::Px(16.0)),
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.8)),
+        BorderColor::all(BUTTON_BORDER),
+        FocusPolicy::Block,
+        GlobalZIndex(60),
+        ResultsUI,
+        GameEntity,
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new(locale.get("results.title")),
+            TextFont { font_size: 32.0, ..default() },
+            TextColor(Color::WHITE),
+            TextLayout::new_with_justify(Justify::Center),
+            LocalizedText("results.title"),
+        ));
+
+        // one row per player, scores appear while counting
+        for (i, (_, name, _, _)) in players.iter().enumerate() {
+            parent.spawn((
+                Node {
+                    justify_content: JustifyContent::SpaceBetween,
+                    padding: UiRect::axes(Val::Px(10.0), Val::Px(4.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::NONE),
+                ResultsRow(i),
+            )).with_children(|row| {
+                row.spawn((
+                    Text::new(name.clone()),
+                    TextFont { font_size: 24.0, ..default() },
+                    TextColor(Color::WHITE),
+                ));
+                row.spawn((
+                    Text::new("-"),
+                    TextFont { font_size: 24.0, ..default() },
+                    TextColor(Color::WHITE),
+                    ResultsScoreText(i),
+                ));
+            });
+        }
+
+        parent.spawn((
+            Text::new(""),
+            TextFont { font_size: 18.0, ..default() },
+            TextColor(Color::srgb(0.85, 0.85, 0.85)),
+            StandingsText,
+        ));
+
+        parent.spawn((
+            Node {
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(8.0),
+                ..default()
+            },
+            ResultsButtons,
+        ));
+    });
+}
+
+pub fn clean_results_screen(
+    mut commands: Commands,
+    results_query: Query<Entity, With<ResultsUI>>,
+) {
+    for entity in results_query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+// reveal the hands one by one, count each total, then show winner and standings
+#[allow(clippy::too_many_arguments)]
+pub fn animate_results(
+    mut commands: Commands,
+    mut reveal: ResMut<ResultsReveal>,
+    mut card_query: Query<&mut Card>,
+    mut score_query: Query<(&mut Text, &ResultsScoreText)>,
+    mut row_query: Query<(&mut BackgroundColor, &ResultsRow)>,
+    mut standings_query: Query<&mut Text, (With<StandingsText>, Without<ResultsScoreText>)>,
+    buttons_query: Query<Entity, With<ResultsButtons>>,
+    result: Res<RoundResult>,
+    standings: Res<MatchStandings>,
+    settings: Res<Settings>,
+    time: Res<Time>,
+    locale: Res<Locale>,
+    mut sounds: MessageWriter<CardSound>,
+) {
+    if reveal.finished {
+        return;
+    }
+
+    if let Some(&(player_entity, score)) = reveal.players.get(reveal.step) {
+        // flip the hand at the start of the step
+        if reveal.timer == 0.0 {
+            reveal_hand(player_entity, &mut card_query);
+            sounds.write(CardSound::Reveal);
+        }
+        reveal.timer += time.delta_secs() * settings.animation_speed;
+
+        // count the score up after the flip
+        let count = ((reveal.timer - REVEAL_TIME) / COUNT_TIME).clamp(0.0, 1.0);
+        let step = reveal.step;
+        for (mut text, score_text) in score_query.iter_mut() {
+            if score_text.0 == step && reveal.timer >= REVEAL_TIME {
+                text.0 = format!("{}", (score as f32 * count).round() as u32);
+            }
+        }
+
+        if count >= 1.0 {
+            reveal.step += 1;
+            reveal.timer = 0.0;
+        }
+        return;
+    }
+
+    reveal.finished = true;
+
+    // winner row
+    let winner_index = reveal.players.iter().position(|(entity, _)| result.winner == Some(*entity));
+    for (mut color, row) in row_query.iter_mut() {
+        if Some(row.0) == winner_index {
+            color.0 = WINNER_COLOR;
+        }
+    }
+
+    // match standings: more wins first, then lower total
+    let mut seats = standings.seats.clone();
+    seats.sort_by(|a, b| b.wins.cmp(&a.wins).then(a.total_score.cmp(&b.total_score)));
+    let lines: Vec<String> = seats.iter().enumerate()
+        .map(|(i, seat)| locale.format("results.standing_line", &[
+            ("rank", &(i + 1)),
+            ("name", &seat.name),
+            ("wins", &seat.wins),
+            ("score", &seat.total_score),
+            ("rounds", &seat.rounds),
+        ]))
+        .collect();
+    if let Ok(mut text) = standings_query.single_mut() {
+        text.0 = format!("{}\n{}", locale.get("results.standings"), lines.join("\n"));
+    }
+
+    if let Ok(container) = buttons_query.single() {
+        commands.entity(container).with_children(|parent| {
+            spawn_localized_button(parent, &locale, "results.next_round", NextRoundButton);
+            spawn_localized_button(parent, &locale, "results.back_to_menu", ResultsMenuButton);
+        });
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn handle_results_buttons(
+    interaction_query: Query<(&Interaction, Option<&NextRoundButton>, Option<&ResultsMenuButton>), Changed<Interaction>>,
+    mut new_round: MessageWriter<NewRoundRequested>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    for (interaction, next_round, menu) in interaction_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        if next_round.is_some() {
+            new_round.write(NewRoundRequested);
+        } else if menu.is_some() {
+            next_state.set(AppState::MainMenu);
+            info!(target: "mygame", "Return to menu...");
+        }
+    }
+}
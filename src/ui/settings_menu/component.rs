@@ -0,0 +1,61 @@
+use bevy::prelude::*;
+use crate::global::settings::resource::SettingKey;
+use crate::global::input::resource::GameAction;
+
+// settings screen root
+#[derive(Component)]
+pub struct SettingsUI;
+
+// container of the rows of the selected tab
+#[derive(Component)]
+pub struct SettingsContent;
+
+// text with the current value of a setting
+#[derive(Component)]
+pub struct SettingValueText(pub SettingKey);
+
+// "<" / ">" buttons of a setting row
+#[derive(Component)]
+pub struct SettingArrow {
+    pub key: SettingKey,
+    pub direction: i32,
+}
+
+// text with the keys bound to an action
+#[derive(Component)]
+pub struct BindingText(pub GameAction);
+
+// rebind (replace keys) or add another key to an action
+#[derive(Component)]
+pub struct RebindButton {
+    pub action: GameAction,
+    pub append: bool,
+}
+
+#[derive(Component)]
+pub struct ResetBindingsButton;
+
+#[derive(Component)]
+pub struct SettingsTabButton(pub SettingsTab);
+
+// close and save
+#[derive(Component)]
+pub struct SettingsBackButton;
+
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum SettingsTab {
+    #[default]
+    General,
+    Deck,
+    Accessibility,
+    Audio,
+    Controls,
+}
+
+// selected tab and action waiting for a key press
+#[derive(Resource, Default)]
+pub struct SettingsScreen {
+    pub tab: SettingsTab,
+    pub listening: Option<RebindButton>,
+    pub keys_released: bool, // the key that started the listening (Confirm) is not taken as the new key
+}
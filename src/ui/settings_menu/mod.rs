@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+use crate::game::gamestate::SettingsState;
+use crate::ui::settings_menu::component::SettingsScreen;
+use crate::ui::settings_menu::system::{spawn_settings_screen, clean_settings_screen, build_settings_content, handle_settings_buttons, listen_rebind_key, update_setting_values};
+
+pub mod component;
+mod system;
+
+pub struct SettingsMenuPlugin;
+
+impl Plugin for SettingsMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<SettingsScreen>()
+            .add_systems(OnEnter(SettingsState::Open), spawn_settings_screen)
+            .add_systems(OnExit(SettingsState::Open), clean_settings_screen)
+            .add_systems(Update, (
+                build_settings_content,
+                handle_settings_buttons,
+                listen_rebind_key,
+                update_setting_values,
+            ).chain().run_if(in_state(SettingsState::Open)));
+    }
+}
@@ -0,0 +1,344 @@
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+use crate::game::gamestate::SettingsState;
+use crate::global::settings::{resource::{Settings, SettingKey}, system::save_settings};
+use crate::global::input::resource::{GameAction, KeyBindings};
+use crate::ui::settings_menu::component::{
+    SettingsUI, SettingsContent, SettingValueText, SettingArrow, SettingsBackButton, BindingText,
+    RebindButton, ResetBindingsButton, SettingsTabButton, SettingsTab, SettingsScreen,
+};
+use crate::global::locale::resource::{Locale, LocalizedText};
+use crate::game::deck_theme::resource::DeckThemes;
+use crate::ui::widget::system::{spawn_text_button, spawn_localized_button};
+
+pub fn spawn_settings_screen(
+    mut commands: Commands,
+    mut screen: ResMut<SettingsScreen>,
+    locale: Res<Locale>,
+) {
+    // always open in the general tab
+    *screen = SettingsScreen::default();
+
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            position_type: PositionType::Absolute,
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(10.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.85)),
+        FocusPolicy::Block,
+        GlobalZIndex(150),
+        SettingsUI,
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new(locale.get("settings.title")),
+            TextFont { font_size: 44.0, ..default() },
+            TextColor(Color::WHITE),
+            LocalizedText("settings.title"),
+        ));
+
+        // tabs
+        parent.spawn(Node { column_gap: Val::Px(12.0), ..default() }).with_children(|row| {
+            spawn_localized_button(row, &locale, "settings.tab.general", SettingsTabButton(SettingsTab::General));
+            spawn_localized_button(row, &locale, "settings.tab.deck", SettingsTabButton(SettingsTab::Deck));
+            spawn_localized_button(row, &locale, "settings.tab.accessibility", SettingsTabButton(SettingsTab::Accessibility));
+            spawn_localized_button(row, &locale, "settings.tab.audio", SettingsTabButton(SettingsTab::Audio));
+            spawn_localized_button(row, &locale, "settings.tab.controls", SettingsTabButton(SettingsTab::Controls));
+        });
+
+        // rows are built by build_settings_content
+        parent.spawn((
+            Node {
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(8.0),
+                ..default()
+            },
+            SettingsContent,
+        ));
+
+        spawn_localized_button(parent, &locale, "settings.back", SettingsBackButton);
+    });
+}
+
+pub fn clean_settings_screen(
+    mut commands: Commands,
+    settings_query: Query<Entity, With<SettingsUI>>,
+) {
+    for entity in settings_query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+fn setting_label(parent: &mut ChildSpawnerCommands, locale: &Locale, key: &'static str) {
+    parent.spawn((
+        Text::new(locale.get(key)),
+        LocalizedText(key),
+        TextFont { font_size: 22.0, ..default() },
+        TextColor(Color::WHITE),
+        Node { width: Val::Px(240.0), ..default() },
+    ));
+}
+
+fn setting_value(parent: &mut ChildSpawnerCommands, value: String, marker: impl Bundle) {
+    parent.spawn((
+        Text::new(value),
+        TextFont { font_size: 22.0, ..default() },
+        TextColor(Color::srgb(1.0, 0.85, 0.5)),
+        TextLayout::new_with_justify(Justify::Center),
+        Node { width: Val::Px(160.0), ..default() },
+        marker,
+    ));
+}
+
+// value of a row, the theme row shows the name from its manifest
+fn row_value(settings: &Settings, locale: &Locale, themes: &DeckThemes, key: SettingKey) -> String {
+    match key {
+        SettingKey::DeckTheme => themes.selected(&settings.deck_theme, settings.deck_type)
+            .map(|theme| theme.manifest.name.clone())
+            .unwrap_or_else(|| locale.get("deck.text_faces")),
+        _ => settings.value_text(key, locale),
+    }
+}
+
+// rebuild the rows when the tab changes
+pub fn build_settings_content(
+    mut commands: Commands,
+    screen: Res<SettingsScreen>,
+    settings: Res<Settings>,
+    locale: Res<Locale>,
+    themes: Res<DeckThemes>,
+    content_query: Query<Entity, With<SettingsContent>>,
+) {
+    if !screen.is_changed() {
+        return;
+    }
+
+    let Ok(content) = content_query.single() else { return; };
+    commands.entity(content).despawn_related::<Children>();
+
+    commands.entity(content).with_children(|parent| {
+        match screen.tab {
+            SettingsTab::General | SettingsTab::Deck | SettingsTab::Accessibility | SettingsTab::Audio => {
+                let keys: &[SettingKey] = match screen.tab {
+                    SettingsTab::Deck => &SettingKey::DECK,
+                    SettingsTab::Accessibility => &SettingKey::ACCESSIBILITY,
+                    SettingsTab::Audio => &SettingKey::AUDIO,
+                    _ => &SettingKey::GENERAL,
+                };
+
+                // label  <  value  >
+                for &key in keys {
+                    parent.spawn(Node {
+                        width: Val::Px(660.0),
+                        align_items: AlignItems::Center,
+                        column_gap: Val::Px(10.0),
+                        ..default()
+                    }).with_children(|row| {
+                        setting_label(row, &locale, key.label_key());
+                        spawn_text_button(row, "<", SettingArrow { key, direction: -1 });
+                        setting_value(row, row_value(&settings, &locale, &themes, key), SettingValueText(key));
+                        spawn_text_button(row, ">", SettingArrow { key, direction: 1 });
+                    });
+                }
+            }
+            SettingsTab::Controls => {
+                // label  keys  rebind  add
+                for action in GameAction::ALL {
+                    parent.spawn(Node {
+                        width: Val::Px(860.0),
+                        align_items: AlignItems::Center,
+                        column_gap: Val::Px(10.0),
+                        ..default()
+                    }).with_children(|row| {
+                        setting_label(row, &locale, action.label_key());
+                        setting_value(row, settings.key_bindings.keys_text(action), BindingText(action));
+                        spawn_localized_button(row, &locale, "settings.rebind", RebindButton { action, append: false });
+                        spawn_localized_button(row, &locale, "settings.add_key", RebindButton { action, append: true });
+                    });
+                }
+                spawn_localized_button(parent, &locale, "settings.reset_keys", ResetBindingsButton);
+            }
+        }
+    });
+}
+
+#[allow(clippy::type_complexity)]
+pub fn handle_settings_buttons(
+    interaction_query: Query<(
+        &Interaction,
+        Option<&SettingArrow>,
+        Option<&SettingsTabButton>,
+        Option<&RebindButton>,
+        Option<&ResetBindingsButton>,
+        Option<&SettingsBackButton>,
+    ), Changed<Interaction>>,
+    mut settings: ResMut<Settings>,
+    mut screen: ResMut<SettingsScreen>,
+    mut next_state: ResMut<NextState<SettingsState>>,
+    themes: Res<DeckThemes>,
+) {
+    for (interaction, arrow, tab, rebind, reset, back) in interaction_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        if let Some(arrow) = arrow {
+            if arrow.key == SettingKey::DeckTheme {
+                if let Some(theme) = themes.cycle(&settings.deck_theme, settings.deck_type, arrow.direction) {
+                    settings.deck_theme = theme;
+                }
+            } else {
+                settings.step(arrow.key, arrow.direction);
+            }
+        } else if let Some(tab) = tab {
+            if screen.tab != tab.0 {
+                screen.tab = tab.0;
+                screen.listening = None;
+            }
+        } else if let Some(rebind) = rebind {
+            // bypass_change_detection: the rows must not be rebuilt
+            let screen = screen.bypass_change_detection();
+            screen.listening = Some(RebindButton { action: rebind.action, append: rebind.append });
+            screen.keys_released = false;
+        } else if reset.is_some() {
+            settings.key_bindings = KeyBindings::default();
+        } else if back.is_some() {
+            save_settings(&settings);
+            next_state.set(SettingsState::Closed);
+            info!(target: "mygame", "Settings saved");
+        }
+    }
+}
+
+// wait for a key press to bind the selected action, Escape cancels
+pub fn listen_rebind_key(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut settings: ResMut<Settings>,
+    mut screen: ResMut<SettingsScreen>,
+    mut text_query: Query<(&mut Text, &BindingText)>,
+    locale: Res<Locale>,
+) {
+    let Some(listening) = &screen.listening else { return; };
+    let action = listening.action;
+    let append = listening.append;
+
+    // keyboard activation: wait until the Confirm key is released, its press is not the new key
+    if !screen.keys_released && keyboard.get_pressed().next().is_none() {
+        screen.bypass_change_detection().keys_released = true;
+    }
+    let pressed = keyboard.get_just_pressed().next().copied().filter(|_| screen.keys_released);
+
+    let Some(key) = pressed else {
+        // show that the row is waiting for a key
+        let prompt = locale.get("settings.press_key");
+        for (mut text, binding) in text_query.iter_mut() {
+            if binding.0 == action && text.0 != prompt {
+                text.0 = prompt.clone();
+            }
+        }
+        return;
+    };
+
+    if key != KeyCode::Escape {
+        if append {
+            settings.key_bindings.add(action, key);
+        } else {
+            settings.key_bindings.bind(action, key);
+        }
+        info!(target: "mygame", "{:?} bound to {:?}", action, key);
+    }
+
+    // write the keys again (also when cancelled)
+    for (mut text, binding) in text_query.iter_mut() {
+        if binding.0 == action {
+            text.0 = settings.key_bindings.keys_text(action);
+        }
+    }
+
+    // bypass_change_detection: the rows must not be rebuilt
+    screen.bypass_change_detection().listening = None;
+}
+
+pub fn update_setting_values(
+    settings: Res<Settings>,
+    locale: Res<Locale>,
+    themes: Res<DeckThemes>,
+    mut value_query: Query<(&mut Text, &SettingValueText), Without<BindingText>>,
+    mut binding_query: Query<(&mut Text, &BindingText), Without<SettingValueText>>,
+) {
+    if !settings.is_changed() && !locale.is_changed() {
+        return;
+    }
+
+    for (mut text, value) in value_query.iter_mut() {
+        text.0 = row_value(&settings, &locale, &themes, value.0);
+    }
+    for (mut text, binding) in binding_query.iter_mut() {
+        text.0 = settings.key_bindings.keys_text(binding.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::global::input::resource::GameAction;
+
+    fn settings_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(bevy::state::app::StatesPlugin)
+            .init_state::<SettingsState>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<Settings>()
+            .init_resource::<SettingsScreen>()
+            .init_resource::<Locale>()
+            .init_resource::<DeckThemes>()
+            .add_systems(Update, (handle_settings_buttons, listen_rebind_key).chain());
+        app
+    }
+
+    // what activate_focus does when Confirm is pressed on a focused button
+    fn confirm_on(app: &mut App, button: Entity) {
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::Space);
+        *app.world_mut().get_mut::<Interaction>(button).unwrap() = Interaction::Pressed;
+        app.update();
+    }
+
+    #[test]
+    fn confirm_on_rebind_does_not_bind_the_confirm_key() {
+        let mut app = settings_app();
+        let action = GameAction::ActivateSpecial;
+        let before = app.world().resource::<Settings>().key_bindings.keys(action);
+        let button = app.world_mut().spawn((Interaction::None, RebindButton { action, append: false })).id();
+
+        confirm_on(&mut app, button);
+
+        assert_eq!(app.world().resource::<Settings>().key_bindings.keys(action), before);
+        assert!(app.world().resource::<SettingsScreen>().listening.is_some());
+    }
+
+    #[test]
+    fn next_key_after_confirm_is_bound() {
+        let mut app = settings_app();
+        let action = GameAction::ActivateSpecial;
+        let button = app.world_mut().spawn((Interaction::None, RebindButton { action, append: false })).id();
+
+        confirm_on(&mut app, button);
+
+        // Confirm released, then the new key
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.release(KeyCode::Space);
+        keyboard.clear();
+        app.update();
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::KeyK);
+        app.update();
+
+        assert_eq!(app.world().resource::<Settings>().key_bindings.keys(action), vec![KeyCode::KeyK]);
+        assert!(app.world().resource::<SettingsScreen>().listening.is_none());
+    }
+}
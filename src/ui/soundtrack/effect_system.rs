@@ -1 +1,90 @@
-use bevy::prelude::*;
\ No newline at end of file
+use bevy::prelude::*;
+use bevy::audio::{PlaybackMode, Volume};
+use rand::Rng;
+use crate::ui::soundtrack::resource::{GameAudio, CardSound, CardSoundPlayer, LastCardSound, AudioMixer};
+use crate::global::settings::resource::Settings;
+
+// sound levels, scaled by the effects bus
+const PLACE_VOLUME: f32 = 0.6;
+const FLIP_VOLUME: f32 = 0.3;
+const SHUFFLE_VOLUME: f32 = 0.7;
+const EFFECT_VOLUME: f32 = 0.5;
+const REVEAL_VOLUME: f32 = 0.35;
+
+// seconds the music stays down after an important sound
+const DUCK_TIME: f32 = 1.2;
+
+// small random pitch change so repeated sounds are not identical
+const PITCH_VARIATION: f32 = 0.08;
+
+// random card-place sample, never the same as the last one
+fn pick_place_variant(audio: &GameAudio, last: &mut LastCardSound) -> Option<Handle<AudioSource>> {
+    let count = audio.card_place.len();
+    if count == 0 {
+        return None;
+    }
+
+    let mut rng = rand::rng();
+    let mut variant = rng.random_range(0..count);
+    if count > 1 && last.variant == Some(variant) {
+        variant = (variant + 1) % count;
+    }
+    last.variant = Some(variant);
+    Some(audio.card_place[variant].clone())
+}
+
+// play one shot per card sound message
+pub fn play_card_sounds(
+    mut commands: Commands,
+    mut sounds: MessageReader<CardSound>,
+    audio: Option<Res<GameAudio>>,
+    mut last: ResMut<LastCardSound>,
+    mut mixer: ResMut<AudioMixer>,
+    settings: Res<Settings>,
+) {
+    let Some(audio) = audio else {
+        sounds.clear();
+        return;
+    };
+
+    let focus = if mixer.focused || !settings.mute_unfocused { 1.0 } else { 0.0 };
+    let bus = settings.effects_gain() * focus;
+
+    for sound in sounds.read() {
+        if sound.ducks_music() {
+            mixer.duck_timer = DUCK_TIME;
+        }
+        if bus <= 0.0 {
+            continue;
+        }
+
+        // sample, level and base speed of each action
+        let (handle, level, speed) = match sound {
+            CardSound::Deal | CardSound::Draw | CardSound::Discard | CardSound::Swap => {
+                let Some(handle) = pick_place_variant(&audio, &mut last) else { continue; };
+                (handle, PLACE_VOLUME, 1.0)
+            }
+            CardSound::Flip => {
+                let Some(handle) = pick_place_variant(&audio, &mut last) else { continue; };
+                (handle, FLIP_VOLUME, 1.4)
+            }
+            CardSound::Shuffle => (audio.card_shuffle.clone(), SHUFFLE_VOLUME, 1.0),
+            CardSound::Effect => (audio.effect.clone(), EFFECT_VOLUME, 1.0),
+            CardSound::Reveal => (audio.effect.clone(), REVEAL_VOLUME, 1.2),
+        };
+
+        let pitch = 1.0 + rand::rng().random_range(-PITCH_VARIATION..PITCH_VARIATION);
+
+        commands.spawn((
+            AudioPlayer::new(handle),
+            PlaybackSettings {
+                mode: PlaybackMode::Despawn, // remove when finished
+                volume: Volume::Linear(level * bus),
+                speed: speed * pitch,
+                ..default()
+            },
+            CardSoundPlayer(*sound),
+        ));
+        debug!(target: "mygame", "Card sound: {:?}", sound);
+    }
+}
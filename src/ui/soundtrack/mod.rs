@@ -1,7 +1,8 @@
 use bevy::prelude::*;
 use crate::game::gamestate::AppState;
-use crate::ui::soundtrack::music_system::{load_audio, play_menu, play_game, stop_music};
-use crate::ui::soundtrack::resource::CurrentMusic;
+use crate::ui::soundtrack::music_system::{load_audio, play_menu, play_game, stop_music, track_window_focus, update_music_mix};
+use crate::ui::soundtrack::effect_system::play_card_sounds;
+use crate::ui::soundtrack::resource::{CurrentMusic, CardSound, LastCardSound, AudioMixer};
 
 pub mod resource;
 mod music_system;
@@ -14,6 +15,9 @@ impl Plugin for AudioPlugin {
         app
             // insert currentMusic resource
             .init_resource::<CurrentMusic>()
+            .init_resource::<LastCardSound>()
+            .init_resource::<AudioMixer>()
+            .add_message::<CardSound>()
             
             // load audio at first
             .add_systems(PreStartup, load_audio)
@@ -29,6 +33,12 @@ impl Plugin for AudioPlugin {
             ))
             
             // stop when leave the game
-            .add_systems(OnEnter(AppState::MainMenu), stop_music);
+            .add_systems(OnEnter(AppState::MainMenu), stop_music)
+
+            // mixer: crossfades, ducking, focus and bus volumes
+            .add_systems(Update, (track_window_focus, update_music_mix).chain())
+
+            // card sound effects
+            .add_systems(Update, play_card_sounds);
     }
 }
\ No newline at end of file
@@ -1,6 +1,19 @@
 use bevy::prelude::*;
 use bevy::audio::{PlaybackMode, Volume};
-use crate::ui::soundtrack::resource::{CurrentMusic, GameAudio};
+use bevy::window::WindowFocused;
+use crate::ui::soundtrack::resource::{CurrentMusic, GameAudio, MusicTrack, AudioMixer};
+use crate::global::settings::resource::Settings;
+
+// track levels, scaled by the music bus
+const MENU_VOLUME: f32 = 0.1;
+const GAME_VOLUME: f32 = 0.4;
+
+// seconds of the crossfade between tracks
+const CROSSFADE_TIME: f32 = 1.5;
+
+// music level under important sounds and how fast it goes down/up
+const DUCK_LEVEL: f32 = 0.35;
+const DUCK_SPEED: f32 = 4.0;
 
 // load audio assets
 pub fn load_audio(
@@ -10,13 +23,42 @@ pub fn load_audio(
     let sound = GameAudio {
         menu: asset_server.load("audio/background/background_menu.wav"),
         game: asset_server.load("audio/background/background_game.wav"),
-        ..default()
+        card_place: vec![
+            asset_server.load("audio/deal/card-place-1.wav"),
+            asset_server.load("audio/deal/card-place-2.wav"),
+            asset_server.load("audio/deal/card-place-3.wav"),
+            asset_server.load("audio/deal/card-place-4.ogg"),
+            asset_server.load("audio/deal/card-place-5.ogg"),
+        ],
+        card_shuffle: asset_server.load("audio/card-shuffle.ogg"),
+        effect: asset_server.load("audio/pounding.wav"),
     };
 
     commands.insert_resource(sound);
     commands.insert_resource(CurrentMusic::default());
 }
 
+// start a track silent, update_music_mix fades it in
+fn spawn_track(
+    commands: &mut Commands,
+    handle: Handle<AudioSource>,
+    base_volume: f32,
+) -> Entity {
+    commands.spawn((
+        AudioPlayer::new(handle),
+        PlaybackSettings {
+            mode: PlaybackMode::Loop, // loop
+            volume: Volume::Linear(0.0),
+            ..default()
+        },
+        MusicTrack {
+            base_volume,
+            fade: 0.0,
+            fading_out: false,
+        },
+    )).id()
+}
+
 // play music in menu
 pub fn play_menu(
     mut commands: Commands,
@@ -39,14 +81,7 @@ pub fn play_menu(
     }
 
     // spawn music
-    let entity = commands.spawn((
-        AudioPlayer::new(audio.menu.clone()),
-        PlaybackSettings {
-            mode: PlaybackMode::Loop, // loop
-            volume: Volume::Linear(0.1), // 30% volume
-            ..default()
-        },
-    )).id();
+    let entity = spawn_track(&mut commands, audio.menu.clone(), MENU_VOLUME);
     info!("Attempting to play menu music!");
 
     current_music.entity = Some(entity);
@@ -65,34 +100,79 @@ pub fn play_game(
     };
 
     // verify if game audio is loaded
-    if !asset_server.is_loaded(&audio.menu) {
+    if !asset_server.is_loaded(&audio.game) {
         return;
     }
-    
+
     if current_music.entity.is_some() {
         return;
     }
 
     // spawn music
-    let entity = commands.spawn((
-        AudioPlayer::new(audio.game.clone()),
-        PlaybackSettings {
-            mode: PlaybackMode::Loop, // loop
-            volume: Volume::Linear(0.4), // 30% volume
-            ..default()
-        },
-    )).id();
+    let entity = spawn_track(&mut commands, audio.game.clone(), GAME_VOLUME);
 
     current_music.entity = Some(entity);
 }
 
-// stop music
+// stop music: the track fades out while the next one fades in
 pub fn stop_music(
-    mut commands: Commands,
     mut current_music: ResMut<CurrentMusic>,
+    mut track_query: Query<&mut MusicTrack>,
+) {
+    if let Some(entity) = current_music.entity.take()
+        && let Ok(mut track) = track_query.get_mut(entity) {
+        track.fading_out = true;
+    }
+}
+
+// the game goes silent in the background
+pub fn track_window_focus(
+    mut focus_events: MessageReader<WindowFocused>,
+    mut mixer: ResMut<AudioMixer>,
+) {
+    for event in focus_events.read() {
+        mixer.focused = event.focused;
+    }
+}
+
+// apply fades, ducking, focus and the music bus to every playing track
+pub fn update_music_mix(
+    mut commands: Commands,
+    mut track_query: Query<(Entity, &mut MusicTrack, Option<&mut AudioSink>)>,
+    mut mixer: ResMut<AudioMixer>,
+    settings: Res<Settings>,
+    time: Res<Time<Real>>,
 ) {
-    if let Some(entity) = current_music.entity {
-        commands.entity(entity).despawn();
-        current_music.entity = None;
+    let delta = time.delta_secs();
+
+    // duck down while the timer runs, then recover
+    mixer.duck_timer = (mixer.duck_timer - delta).max(0.0);
+    let duck_target = if mixer.duck_timer > 0.0 { DUCK_LEVEL } else { 1.0 };
+    let duck_step = DUCK_SPEED * delta;
+    mixer.duck = if mixer.duck < duck_target {
+        (mixer.duck + duck_step).min(duck_target)
+    } else {
+        (mixer.duck - duck_step).max(duck_target)
+    };
+
+    let focus = if mixer.focused || !settings.mute_unfocused { 1.0 } else { 0.0 };
+    let bus = settings.music_gain() * mixer.duck * focus;
+
+    for (entity, mut track, sink) in track_query.iter_mut() {
+        let fade_step = delta / CROSSFADE_TIME;
+        if track.fading_out {
+            track.fade = (track.fade - fade_step).max(0.0);
+            if track.fade <= 0.0 {
+                commands.entity(entity).despawn();
+                continue;
+            }
+        } else {
+            track.fade = (track.fade + fade_step).min(1.0);
+        }
+
+        // the sink appears once playback has started
+        if let Some(mut sink) = sink {
+            sink.set_volume(Volume::Linear(track.base_volume * track.fade * bus));
+        }
     }
-}
\ No newline at end of file
+}
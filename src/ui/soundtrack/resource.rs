@@ -5,19 +5,65 @@ use bevy::prelude::*;
 pub struct GameAudio {
     pub menu: Handle<AudioSource>,
     pub game: Handle<AudioSource>,
-    pub card_place: Handle<AudioSource>,
-    pub card_deal: Handle<AudioSource>,
-    pub random: Handle<AudioSource>,
+    pub card_place: Vec<Handle<AudioSource>>, // variants, one is picked at random
+    pub card_shuffle: Handle<AudioSource>,
+    pub effect: Handle<AudioSource>,
 }
 
 // manage if music is playing, for default: none
-#[derive(Resource)]
+#[derive(Resource, Default)]
 pub struct CurrentMusic {
     pub entity: Option<Entity>,
 }
 
-impl Default for CurrentMusic {
+// a playing music track, fades in when spawned and out when replaced
+#[derive(Component)]
+pub struct MusicTrack {
+    pub base_volume: f32, // track level before the music bus
+    pub fade: f32, // 0.0 - 1.0
+    pub fading_out: bool,
+}
+
+// runtime state of the mixer (volumes and mutes live in Settings)
+#[derive(Resource)]
+pub struct AudioMixer {
+    pub focused: bool, // window focus, the game is silent in the background
+    pub duck: f32, // current music multiplier, lower while important sounds play
+    pub duck_timer: f32, // seconds left of ducking
+}
+
+impl Default for AudioMixer {
     fn default() -> Self {
-        Self { entity: None }
+        Self { focused: true, duck: 1.0, duck_timer: 0.0 }
     }
-}
\ No newline at end of file
+}
+
+// card actions that make a sound
+#[derive(Message, Clone, Copy, Debug, PartialEq)]
+pub enum CardSound {
+    Deal,
+    Draw,
+    Discard,
+    Swap,
+    Flip,
+    Shuffle,
+    Effect,
+    Reveal, // a hand revealed at round end
+}
+
+// one shot playing a card sound, so it can be cut (skipped shuffle)
+#[derive(Component)]
+pub struct CardSoundPlayer(pub CardSound);
+
+impl CardSound {
+    // important sounds lower the music for a moment
+    pub fn ducks_music(&self) -> bool {
+        matches!(self, CardSound::Shuffle | CardSound::Effect | CardSound::Reveal)
+    }
+}
+
+// last card-place variant, so the same sample is not played twice in a row
+#[derive(Resource, Default)]
+pub struct LastCardSound {
+    pub variant: Option<usize>,
+}
@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+
+// stats screen root
+#[derive(Component)]
+pub struct StatsUI;
+
+// text with the stats of the browsed profile
+#[derive(Component)]
+pub struct StatsText;
+
+// buttons
+#[derive(Component)]
+pub struct PrevProfileButton;
+
+#[derive(Component)]
+pub struct NextProfileButton;
+
+#[derive(Component)]
+pub struct SeatButton(pub usize); // seat index (0 = player 1)
+
+#[derive(Component)]
+pub struct NewProfileButton;
+
+#[derive(Component)]
+pub struct StatsBackButton;
+
+// profile being browsed and name being typed for a new profile
+#[derive(Resource, Default)]
+pub struct StatsBrowser {
+    pub index: usize,
+    pub typing: Option<String>,
+}
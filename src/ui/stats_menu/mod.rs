@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+use crate::game::gamestate::MenuPage;
+use crate::ui::stats_menu::component::StatsBrowser;
+use crate::ui::stats_menu::system::{spawn_stats_screen, clean_stats_screen, handle_stats_buttons, type_profile_name, update_stats_text};
+
+pub mod component;
+mod system;
+
+pub struct StatsMenuPlugin;
+
+impl Plugin for StatsMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<StatsBrowser>()
+            .add_systems(OnEnter(MenuPage::Stats), spawn_stats_screen)
+            .add_systems(OnExit(MenuPage::Stats), clean_stats_screen)
+            .add_systems(Update, (handle_stats_buttons, type_profile_name, update_stats_text).chain().run_if(in_state(MenuPage::Stats)));
+    }
+}
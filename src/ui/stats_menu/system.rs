@@ -0,0 +1,209 @@
+use bevy::prelude::*;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::ui::FocusPolicy;
+use crate::game::gamestate::MenuPage;
+use crate::game::profile::{resource::Profiles, system::save_profiles};
+use crate::ui::stats_menu::component::{StatsUI, StatsText, PrevProfileButton, NextProfileButton, SeatButton, NewProfileButton, StatsBackButton, StatsBrowser};
+use crate::global::locale::resource::{Locale, LocalizedText};
+use crate::ui::widget::system::{spawn_text_button, spawn_localized_button};
+
+// max characters of a profile name
+const MAX_NAME_LEN: usize = 16;
+
+pub fn spawn_stats_screen(
+    mut commands: Commands,
+    mut browser: ResMut<StatsBrowser>,
+    profiles: Res<Profiles>,
+    locale: Res<Locale>,
+) {
+    // start browsing the profile of player 1
+    browser.index = profiles.seat_profile(0);
+    browser.typing = None;
+
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            position_type: PositionType::Absolute,
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(16.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.85)),
+        FocusPolicy::Block,
+        GlobalZIndex(50),
+        StatsUI,
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new(locale.get("stats.title")),
+            TextFont { font_size: 44.0, ..default() },
+            TextColor(Color::WHITE),
+            LocalizedText("stats.title"),
+        ));
+
+        parent.spawn((
+            Text::new(""),
+            TextFont { font_size: 22.0, ..default() },
+            TextColor(Color::srgb(0.9, 0.9, 0.9)),
+            TextLayout::new_with_justify(Justify::Center),
+            StatsText,
+        ));
+
+        // browse
+        parent.spawn(Node { column_gap: Val::Px(12.0), ..default() }).with_children(|row| {
+            spawn_text_button(row, "<", PrevProfileButton);
+            spawn_text_button(row, ">", NextProfileButton);
+        });
+
+        // seats
+        parent.spawn(Node { column_gap: Val::Px(12.0), ..default() }).with_children(|row| {
+            spawn_text_button(row, locale.format("stats.use_as_player", &[("seat", &1)]), SeatButton(0));
+            spawn_text_button(row, locale.format("stats.use_as_player", &[("seat", &2)]), SeatButton(1));
+        });
+
+        parent.spawn(Node { column_gap: Val::Px(12.0), ..default() }).with_children(|row| {
+            spawn_localized_button(row, &locale, "stats.new_profile", NewProfileButton);
+            spawn_localized_button(row, &locale, "stats.back", StatsBackButton);
+        });
+    });
+}
+
+pub fn clean_stats_screen(
+    mut commands: Commands,
+    stats_query: Query<Entity, With<StatsUI>>,
+) {
+    for entity in stats_query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn handle_stats_buttons(
+    interaction_query: Query<(
+        &Interaction,
+        Option<&PrevProfileButton>,
+        Option<&NextProfileButton>,
+        Option<&SeatButton>,
+        Option<&NewProfileButton>,
+        Option<&StatsBackButton>,
+    ), Changed<Interaction>>,
+    mut browser: ResMut<StatsBrowser>,
+    mut profiles: ResMut<Profiles>,
+    mut next_page: ResMut<NextState<MenuPage>>,
+) {
+    let count = profiles.profiles.len();
+
+    for (interaction, prev, next, seat, new_profile, back) in interaction_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        if prev.is_some() {
+            browser.index = (browser.index + count - 1) % count;
+        } else if next.is_some() {
+            browser.index = (browser.index + 1) % count;
+        } else if let Some(seat) = seat {
+            // the same profile cannot sit in both seats
+            let other = 1 - seat.0;
+            if profiles.seats[other] == browser.index {
+                profiles.seats[other] = profiles.seats[seat.0];
+            }
+            profiles.seats[seat.0] = browser.index;
+            save_profiles(&profiles);
+            info!(target: "mygame", "Profile {} sits as player {}", profiles.profiles[browser.index].name, seat.0 + 1);
+        } else if new_profile.is_some() {
+            browser.typing = Some(String::new());
+        } else if back.is_some() {
+            next_page.set(MenuPage::Main);
+        }
+    }
+}
+
+// type the name of a new profile, Enter to create and Escape to cancel
+pub fn type_profile_name(
+    mut keyboard_events: MessageReader<KeyboardInput>,
+    mut browser: ResMut<StatsBrowser>,
+    mut profiles: ResMut<Profiles>,
+) {
+    let Some(mut name) = browser.typing.clone() else {
+        keyboard_events.clear();
+        return;
+    };
+
+    for event in keyboard_events.read() {
+        if !event.state.is_pressed() {
+            continue;
+        }
+
+        match &event.logical_key {
+            Key::Enter => {
+                let trimmed = name.trim();
+                if !trimmed.is_empty() {
+                    browser.index = profiles.add_profile(trimmed);
+                    save_profiles(&profiles);
+                    info!(target: "mygame", "Profile created: {}", trimmed);
+                }
+                browser.typing = None;
+                return;
+            }
+            Key::Escape => {
+                browser.typing = None;
+                return;
+            }
+            Key::Backspace => {
+                name.pop();
+            }
+            Key::Space if name.len() < MAX_NAME_LEN => {
+                name.push(' ');
+            }
+            Key::Character(chars) if name.len() + chars.len() <= MAX_NAME_LEN => {
+                name.push_str(chars);
+            }
+            _ => {}
+        }
+    }
+
+    browser.typing = Some(name);
+}
+
+pub fn update_stats_text(
+    browser: Res<StatsBrowser>,
+    profiles: Res<Profiles>,
+    locale: Res<Locale>,
+    mut text_query: Query<&mut Text, With<StatsText>>,
+) {
+    if !browser.is_changed() && !profiles.is_changed() && !locale.is_changed() {
+        return;
+    }
+
+    let Ok(mut text) = text_query.single_mut() else { return; };
+
+    if let Some(name) = &browser.typing {
+        text.0 = locale.format("stats.new_profile_prompt", &[("name", name)]);
+        return;
+    }
+
+    let Some(profile) = profiles.profiles.get(browser.index) else { return; };
+    let stats = &profile.stats;
+
+    // seat label
+    let seat = profiles.seats.iter()
+        .position(|&s| s == browser.index)
+        .map(|s| locale.format("stats.seat", &[("seat", &(s + 1))]))
+        .unwrap_or_default();
+
+    text.0 = locale.format("stats.body", &[
+        ("name", &profile.name),
+        ("seat", &seat),
+        ("rounds", &stats.rounds_played),
+        ("wins", &stats.wins),
+        ("average", &format!("{:.1}", stats.average_score())),
+        ("effects", &stats.special_effects_used),
+        ("calls_won", &stats.round_calls_won),
+        ("calls_lost", &stats.round_calls_lost),
+        ("index", &(browser.index + 1)),
+        ("count", &profiles.profiles.len()),
+    ]);
+}
@@ -0,0 +1,69 @@
+use std::collections::VecDeque;
+use bevy::prelude::*;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ToastSeverity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl ToastSeverity {
+    pub fn color(&self) -> Color {
+        match self {
+            ToastSeverity::Info => Color::srgba(0.12, 0.16, 0.22, 0.9),
+            ToastSeverity::Warning => Color::srgba(0.45, 0.32, 0.05, 0.9),
+            ToastSeverity::Error => Color::srgba(0.5, 0.1, 0.1, 0.9),
+        }
+    }
+
+    // seconds on screen
+    pub fn duration(&self) -> f32 {
+        match self {
+            ToastSeverity::Info => 2.5,
+            ToastSeverity::Warning => 3.0,
+            ToastSeverity::Error => 4.0,
+        }
+    }
+}
+
+// short message shown over the game, any system can write it
+#[derive(Message, Clone)]
+pub struct Toast {
+    pub text: String, // string table key, shown as is when no table has it
+    pub severity: ToastSeverity,
+}
+
+impl Toast {
+    pub fn info(text: impl Into<String>) -> Self {
+        Self { text: text.into(), severity: ToastSeverity::Info }
+    }
+
+    pub fn warning(text: impl Into<String>) -> Self {
+        Self { text: text.into(), severity: ToastSeverity::Warning }
+    }
+
+    pub fn error(text: impl Into<String>) -> Self {
+        Self { text: text.into(), severity: ToastSeverity::Error }
+    }
+}
+
+// toasts waiting for a free place on screen
+#[derive(Resource, Default)]
+pub struct ToastQueue {
+    pub pending: VecDeque<Toast>,
+}
+
+// column holding the visible toasts
+#[derive(Component)]
+pub struct ToastRoot;
+
+#[derive(Component)]
+pub struct ToastEntry {
+    pub text: String,
+    pub elapsed: f32,
+    pub duration: f32,
+}
+
+pub const MAX_VISIBLE_TOASTS: usize = 3;
+pub const TOAST_FADE_TIME: f32 = 0.3;
@@ -0,0 +1,18 @@
+use bevy::prelude::*;
+use crate::ui::toast::component::{Toast, ToastQueue};
+use crate::ui::toast::system::{spawn_toast_root, queue_toasts, show_toasts, update_toasts};
+
+pub mod component;
+mod system;
+
+pub struct ToastPlugin;
+
+impl Plugin for ToastPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_message::<Toast>()
+            .init_resource::<ToastQueue>()
+            .add_systems(Startup, spawn_toast_root)
+            .add_systems(Update, (queue_toasts, show_toasts, update_toasts).chain());
+    }
+}
@@ -0,0 +1,102 @@
+use bevy::prelude::*;
+use crate::global::locale::resource::Locale;
+use crate::ui::toast::component::{Toast, ToastQueue, ToastRoot, ToastEntry, MAX_VISIBLE_TOASTS, TOAST_FADE_TIME};
+
+// toasts live for the whole app (menus and game)
+pub fn spawn_toast_root(mut commands: Commands) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Percent(4.0),
+            width: Val::Percent(100.0),
+            flex_direction: FlexDirection::ColumnReverse,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(6.0),
+            ..default()
+        },
+        GlobalZIndex(300),
+        Pickable::IGNORE,
+        ToastRoot,
+    ));
+}
+
+// read new toasts, the same text already on screen only restarts its timer
+pub fn queue_toasts(
+    mut toasts: MessageReader<Toast>,
+    mut queue: ResMut<ToastQueue>,
+    mut entry_query: Query<&mut ToastEntry>,
+) {
+    for toast in toasts.read() {
+        if let Some(mut entry) = entry_query.iter_mut().find(|entry| entry.text == toast.text) {
+            entry.elapsed = 0.0;
+            continue;
+        }
+        if queue.pending.iter().any(|pending| pending.text == toast.text) {
+            continue;
+        }
+        queue.pending.push_back(toast.clone());
+    }
+}
+
+pub fn show_toasts(
+    mut commands: Commands,
+    mut queue: ResMut<ToastQueue>,
+    root_query: Query<Entity, With<ToastRoot>>,
+    entry_query: Query<&ToastEntry>,
+    locale: Res<Locale>,
+) {
+    let Ok(root) = root_query.single() else { return; };
+    let mut visible = entry_query.iter().count();
+
+    while visible < MAX_VISIBLE_TOASTS {
+        let Some(toast) = queue.pending.pop_front() else { break; };
+        visible += 1;
+
+        commands.entity(root).with_children(|parent| {
+            parent.spawn((
+                Node {
+                    padding: UiRect::axes(Val::Px(16.0), Val::Px(8.0)),
+                    ..default()
+                },
+                BackgroundColor(toast.severity.color()),
+                Pickable::IGNORE,
+                ToastEntry {
+                    text: toast.text.clone(),
+                    elapsed: 0.0,
+                    duration: toast.severity.duration(),
+                },
+                children![(
+                    Text::new(locale.get(&toast.text)),
+                    TextFont { font_size: 20.0, ..default() },
+                    TextColor(Color::WHITE),
+                    Pickable::IGNORE,
+                )],
+            ));
+        });
+    }
+}
+
+// fade out at the end and remove (real time, toasts keep going while paused)
+pub fn update_toasts(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    mut entry_query: Query<(Entity, &mut ToastEntry, &mut BackgroundColor, &Children)>,
+    mut text_query: Query<&mut TextColor>,
+) {
+    for (entity, mut entry, mut background, children) in entry_query.iter_mut() {
+        entry.elapsed += time.delta_secs();
+
+        if entry.elapsed >= entry.duration {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let alpha = ((entry.duration - entry.elapsed) / TOAST_FADE_TIME).min(1.0);
+        background.0.set_alpha(0.9 * alpha);
+        for child in children.iter() {
+            if let Ok(mut color) = text_query.get_mut(child) {
+                color.0.set_alpha(alpha);
+            }
+        }
+    }
+}
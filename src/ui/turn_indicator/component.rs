@@ -1,11 +1,11 @@
 use bevy::prelude::*;
 
-// opacity animation
+// opacity animation, played by a Fade animator (see ui/tween)
 #[derive(Component)]
 pub struct CardOpacity {
-    pub current: f32,
+    pub current: f32, // opacity the running fade goes to
     pub target: f32,
-    pub transition_speed: f32, // How fast the opacity changes
+    pub transition_speed: f32, // How fast the opacity changes (full range per second)
 }
 
 // default values
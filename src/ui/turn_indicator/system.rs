@@ -2,6 +2,9 @@ use bevy::prelude::*;
 use crate::ui::turn_indicator::component::CardOpacity;
 use crate::game::card::component::{Card, CardPosition};
 use crate::game::turn_player::component::Turn;
+use crate::game::special_cards::resource::SpecialCardEffect;
+use crate::ui::effect_prompt::component::DIMMED_OPACITY;
+use crate::ui::tween::component::{Animator, Ease, Fade, Tween, TweenKind};
 
 // add CardOpacity component to cards
 pub fn add_card_opacity(
@@ -17,7 +20,16 @@ pub fn add_card_opacity(
 pub fn update_turn_indicator(
     mut card_query: Query<(&Card, &mut CardOpacity)>,
     turn_query: Res<Turn>,
+    special_effect: Option<Res<SpecialCardEffect>>,
 ) {
+    // choosing a special effect target: valid cards lit, the rest dimmed
+    if let Some(effect) = special_effect.filter(|effect| effect.is_targeting()) {
+        for (card, mut opacity) in card_query.iter_mut() {
+            opacity.target = if effect.is_valid_target(card, turn_query.current_player) { 1.0 } else { DIMMED_OPACITY };
+        }
+        return;
+    }
+
     for (card, mut opacity) in card_query.iter_mut() {
         if let CardPosition::Hand(hand_owner) = card.position {
             if hand_owner == turn_query.current_player {
@@ -34,20 +46,22 @@ pub fn update_turn_indicator(
     }
 }
 
-// opacity animation
+// opacity animation: fade from the shown alpha when the target changes
 pub fn animation_opacity(
-    mut card_query: Query<(&mut CardOpacity, &mut Sprite)>,
-    time: Res<Time>
+    mut commands: Commands,
+    mut card_query: Query<(Entity, &mut CardOpacity, &Sprite)>,
 ) {
-    for (mut opacity, mut sprite) in card_query.iter_mut() {
-        let result = opacity.target - opacity.current;
-
-        if result.abs() > 0.01 {
-            // update current and sprite
-            opacity.current += result * opacity.transition_speed * time.delta_secs();
-            sprite.color.set_alpha(opacity.current);
-        } else {
-            opacity.current = opacity.target;
+    for (entity, mut opacity, sprite) in card_query.iter_mut() {
+        if (opacity.target - opacity.current).abs() <= 0.01 {
+            continue;
         }
+
+        let from = sprite.color.alpha();
+        let duration = (opacity.target - from).abs() / opacity.transition_speed;
+        commands.entity(entity).insert(Animator::<Fade>::new(
+            TweenKind::Fade,
+            Tween::alpha(from, opacity.target, duration).ease(Ease::QuadOut),
+        ));
+        opacity.current = opacity.target;
     }
 }
\ No newline at end of file
@@ -0,0 +1,233 @@
+use std::marker::PhantomData;
+use bevy::prelude::*;
+
+// easing curves, t goes from 0.0 to 1.0
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum Ease {
+    #[default]
+    Linear,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicOut,
+    SmoothStep,
+    BackOut, // passes the end a little and comes back
+}
+
+impl Ease {
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Ease::Linear => t,
+            Ease::QuadIn => t * t,
+            Ease::QuadOut => 1.0 - (1.0 - t) * (1.0 - t),
+            Ease::QuadInOut => {
+                if t < 0.5 { 2.0 * t * t } else { 1.0 - (-2.0 * t + 2.0).powi(2) / 2.0 }
+            }
+            Ease::CubicOut => 1.0 - (1.0 - t).powi(3),
+            Ease::SmoothStep => t * t * (3.0 - 2.0 * t),
+            Ease::BackOut => {
+                let c1 = 1.70158;
+                let c3 = c1 + 1.0;
+                1.0 + c3 * (t - 1.0).powi(3) + c1 * (t - 1.0).powi(2)
+            }
+        }
+    }
+}
+
+// property changed by a step, from -> to
+#[derive(Clone, Debug)]
+pub enum Track {
+    Translation(Vec3, Vec3),
+    Rotation(Quat, Quat),
+    Scale(Vec3, Vec3),
+    Alpha(f32, f32), // sprite alpha, keeps the tint
+}
+
+// tree of steps: sequences run one after the other, parallel groups at the same time
+#[derive(Clone, Debug)]
+pub enum Tween {
+    Step { track: Track, duration: f32, ease: Ease },
+    Delay(f32),
+    Mark(&'static str), // sends a TweenMarker when reached
+    Sequence(Vec<Tween>),
+    Parallel(Vec<Tween>),
+}
+
+impl Tween {
+    pub fn translation(from: Vec3, to: Vec3, duration: f32) -> Self {
+        Tween::Step { track: Track::Translation(from, to), duration, ease: Ease::Linear }
+    }
+
+    pub fn rotation(from: Quat, to: Quat, duration: f32) -> Self {
+        Tween::Step { track: Track::Rotation(from, to), duration, ease: Ease::Linear }
+    }
+
+    pub fn scale(from: Vec3, to: Vec3, duration: f32) -> Self {
+        Tween::Step { track: Track::Scale(from, to), duration, ease: Ease::Linear }
+    }
+
+    pub fn alpha(from: f32, to: f32, duration: f32) -> Self {
+        Tween::Step { track: Track::Alpha(from, to), duration, ease: Ease::Linear }
+    }
+
+    pub fn delay(duration: f32) -> Self {
+        Tween::Delay(duration)
+    }
+
+    pub fn mark(name: &'static str) -> Self {
+        Tween::Mark(name)
+    }
+
+    pub fn sequence(tweens: impl IntoIterator<Item = Tween>) -> Self {
+        Tween::Sequence(tweens.into_iter().collect())
+    }
+
+    pub fn parallel(tweens: impl IntoIterator<Item = Tween>) -> Self {
+        Tween::Parallel(tweens.into_iter().collect())
+    }
+
+    // easing of a step (groups keep the easing of their steps)
+    pub fn ease(mut self, curve: Ease) -> Self {
+        if let Tween::Step { ease, .. } = &mut self {
+            *ease = curve;
+        }
+        self
+    }
+
+    // seconds at normal speed
+    pub fn duration(&self) -> f32 {
+        match self {
+            Tween::Step { duration, .. } | Tween::Delay(duration) => *duration,
+            Tween::Mark(_) => 0.0,
+            Tween::Sequence(tweens) => tweens.iter().map(Tween::duration).sum(),
+            Tween::Parallel(tweens) => tweens.iter().map(Tween::duration).fold(0.0, f32::max),
+        }
+    }
+
+    // set the values at a time; steps not started yet are left alone
+    pub fn apply(&self, time: f32, transform: &mut Transform, sprite: &mut Option<&mut Sprite>) {
+        match self {
+            Tween::Step { track, duration, ease } => {
+                let t = if *duration <= 0.0 { 1.0 } else { ease.apply(time / duration) };
+                match track {
+                    Track::Translation(from, to) => transform.translation = from.lerp(*to, t),
+                    Track::Rotation(from, to) => transform.rotation = from.slerp(*to, t),
+                    Track::Scale(from, to) => transform.scale = from.lerp(*to, t),
+                    Track::Alpha(from, to) => {
+                        if let Some(sprite) = sprite.as_deref_mut() {
+                            sprite.color.set_alpha(from + (to - from) * t);
+                        }
+                    }
+                }
+            }
+            Tween::Delay(_) | Tween::Mark(_) => {}
+            Tween::Sequence(tweens) => {
+                let mut start = 0.0;
+                for tween in tweens {
+                    if time < start {
+                        break;
+                    }
+                    tween.apply(time - start, transform, sprite);
+                    start += tween.duration();
+                }
+            }
+            Tween::Parallel(tweens) => {
+                for tween in tweens {
+                    tween.apply(time, transform, sprite);
+                }
+            }
+        }
+    }
+
+    // marks placed in [from, to), offset = start time of this tween
+    pub fn marks(&self, offset: f32, from: f32, to: f32, found: &mut Vec<&'static str>) {
+        match self {
+            Tween::Mark(name) => {
+                if offset >= from && offset < to {
+                    found.push(name);
+                }
+            }
+            Tween::Step { .. } | Tween::Delay(_) => {}
+            Tween::Sequence(tweens) => {
+                let mut start = offset;
+                for tween in tweens {
+                    tween.marks(start, from, to, found);
+                    start += tween.duration();
+                }
+            }
+            Tween::Parallel(tweens) => {
+                for tween in tweens {
+                    tween.marks(offset, from, to, found);
+                }
+            }
+        }
+    }
+}
+
+// what an animator is doing, sent back in the tween messages
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TweenKind {
+    Deal,
+    Flip,
+    Movement,
+    Fade,
+    Shuffle,
+    Effect,
+}
+
+// animators of different channels run at the same time on one entity
+pub trait TweenChannel: Send + Sync + 'static {}
+
+// card movement, flip and deal
+pub struct Motion;
+impl TweenChannel for Motion {}
+
+// sprite opacity
+pub struct Fade;
+impl TweenChannel for Fade {}
+
+// particles and highlights, never block the board
+pub struct Vfx;
+impl TweenChannel for Vfx {}
+
+// plays a tween on the Transform (and Sprite) of its entity, removed when finished
+#[derive(Component)]
+pub struct Animator<C: TweenChannel> {
+    pub tween: Tween,
+    pub kind: TweenKind,
+    pub elapsed: f32,
+    channel: PhantomData<C>,
+}
+
+impl<C: TweenChannel> Animator<C> {
+    pub fn new(kind: TweenKind, tween: Tween) -> Self {
+        Self { tween, kind, elapsed: 0.0, channel: PhantomData }
+    }
+
+    // 0.0 - 1.0
+    pub fn progress(&self) -> f32 {
+        let duration = self.tween.duration();
+        if duration <= 0.0 { 1.0 } else { (self.elapsed / duration).min(1.0) }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.tween.duration()
+    }
+}
+
+// a Tween::Mark was reached
+#[derive(Message, Clone, Copy, Debug)]
+pub struct TweenMarker {
+    #[allow(dead_code)] // the sounds only look at the kind, kept so handlers can tell animators apart
+    pub entity: Entity,
+    pub kind: TweenKind,
+    pub name: &'static str,
+}
+
+// an animator finished (not sent when it is replaced or removed before)
+#[derive(Message, Clone, Copy, Debug)]
+pub struct TweenCompleted {
+    pub entity: Entity,
+    pub kind: TweenKind,
+}
@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+
+pub mod component;
+mod system;
+
+use crate::ui::tween::component::{Motion, Fade, Vfx, TweenCompleted, TweenMarker};
+use crate::ui::tween::system::run_tweens;
+
+// handlers of the tween messages run after this set
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+pub struct TweenSet;
+
+pub struct TweenPlugin;
+
+impl Plugin for TweenPlugin {
+    fn build(&self, app: &mut App) {
+        app
+        .add_message::<TweenMarker>()
+        .add_message::<TweenCompleted>()
+        // chained so the last writer is fixed: a Fade on the same sprite owns its alpha over Motion
+        .add_systems(Update, (run_tweens::<Motion>, run_tweens::<Fade>, run_tweens::<Vfx>).chain().in_set(TweenSet));
+    }
+}
@@ -0,0 +1,60 @@
+use bevy::prelude::*;
+use crate::global::settings::resource::Settings;
+use crate::ui::tween::component::{Animator, TweenChannel, TweenCompleted, TweenKind, TweenMarker};
+
+// reduced motion: card animations this much faster
+const REDUCED_MOTION_SPEED: f32 = 3.0;
+
+// speed of an animator for the reduced motion setting, None when it should end at once
+fn motion_speed(kind: TweenKind, settings: &Settings) -> Option<f32> {
+    if !settings.reduced_motion {
+        return Some(1.0);
+    }
+    match kind {
+        TweenKind::Deal | TweenKind::Flip | TweenKind::Movement => Some(REDUCED_MOTION_SPEED),
+        TweenKind::Fade | TweenKind::Shuffle => None,
+        TweenKind::Effect => Some(1.0), // effects are already reduced when spawned
+    }
+}
+
+// advance every animator of a channel, game time so the pause freezes them
+pub fn run_tweens<C: TweenChannel>(
+    mut commands: Commands,
+    mut animator_query: Query<(Entity, &mut Animator<C>, &mut Transform, Option<&mut Sprite>)>,
+    time: Res<Time>,
+    settings: Res<Settings>,
+    mut markers: MessageWriter<TweenMarker>,
+    mut completed: MessageWriter<TweenCompleted>,
+) {
+    for (entity, mut animator, mut transform, sprite) in animator_query.iter_mut() {
+        let previous = animator.elapsed;
+        animator.elapsed = match motion_speed(animator.kind, &settings) {
+            Some(speed) => animator.elapsed + time.delta_secs() * settings.animation_speed * speed,
+            None => animator.elapsed.max(animator.tween.duration()),
+        };
+
+        let finished = animator.is_finished();
+        let time = if finished { animator.tween.duration() } else { animator.elapsed };
+        let mut sprite = sprite.map(|sprite| sprite.into_inner());
+        animator.tween.apply(time, &mut transform, &mut sprite);
+
+        // marks at the very end are sent before the completion
+        let mut found = Vec::new();
+        let until = if finished { f32::INFINITY } else { animator.elapsed };
+        animator.tween.marks(0.0, previous, until, &mut found);
+        for name in found {
+            markers.write(TweenMarker { entity, kind: animator.kind, name });
+        }
+
+        if finished {
+            completed.write(TweenCompleted { entity, kind: animator.kind });
+            // a handler may have inserted the next animation meanwhile, keep it
+            // silenced: the entity may be despawned before the command applies
+            commands.entity(entity).queue_silenced(|mut entity: EntityWorldMut| {
+                if entity.get::<Animator<C>>().is_some_and(|animator| animator.is_finished()) {
+                    entity.remove::<Animator<C>>();
+                }
+            });
+        }
+    }
+}
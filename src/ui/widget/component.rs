@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+
+// text button built with bevy ui
+#[derive(Component)]
+pub struct TextButton;
+
+// button colors
+pub const BUTTON_NORMAL: Color = Color::srgb(0.16, 0.12, 0.09);
+pub const BUTTON_HOVERED: Color = Color::srgb(0.30, 0.22, 0.14);
+pub const BUTTON_PRESSED: Color = Color::srgb(0.45, 0.33, 0.18);
+pub const BUTTON_BORDER: Color = Color::srgb(0.75, 0.6, 0.35);
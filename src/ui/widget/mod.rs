@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+
+pub mod component;
+pub mod system;
+use system::text_button_visuals;
+
+pub struct WidgetPlugin;
+
+impl Plugin for WidgetPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, text_button_visuals);
+    }
+}
@@ -0,0 +1,65 @@
+use bevy::prelude::*;
+use crate::ui::widget::component::{TextButton, BUTTON_NORMAL, BUTTON_HOVERED, BUTTON_PRESSED, BUTTON_BORDER};
+use crate::ui::focus::component::Focusable;
+use crate::global::locale::resource::{Locale, LocalizedText};
+
+// spawn a text button as child, `marker` identifies the action of the button
+pub fn spawn_text_button(
+    parent: &mut ChildSpawnerCommands,
+    label: impl Into<String>,
+    marker: impl Bundle,
+) -> Entity {
+    spawn_button(parent, Text::new(label), marker)
+}
+
+// text button whose label follows the selected language
+pub fn spawn_localized_button(
+    parent: &mut ChildSpawnerCommands,
+    locale: &Locale,
+    key: &'static str,
+    marker: impl Bundle,
+) -> Entity {
+    spawn_button(parent, (Text::new(locale.get(key)), LocalizedText(key)), marker)
+}
+
+fn spawn_button(
+    parent: &mut ChildSpawnerCommands,
+    text: impl Bundle,
+    marker: impl Bundle,
+) -> Entity {
+    parent.spawn((
+        Button,
+        Node {
+            min_width: Val::Px(180.0),
+            padding: UiRect::axes(Val::Px(18.0), Val::Px(8.0)),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BorderColor::all(BUTTON_BORDER),
+        BackgroundColor(BUTTON_NORMAL),
+        TextButton,
+        Focusable,
+        marker,
+        children![(
+            text,
+            TextFont { font_size: 22.0, ..default() },
+            TextColor(Color::WHITE),
+        )],
+    )).id()
+}
+
+// update button color with interaction
+#[allow(clippy::type_complexity)]
+pub fn text_button_visuals(
+    mut button_query: Query<(&Interaction, &mut BackgroundColor), (Changed<Interaction>, With<TextButton>)>,
+) {
+    for (interaction, mut color) in &mut button_query {
+        color.0 = match interaction {
+            Interaction::Pressed => BUTTON_PRESSED,
+            Interaction::Hovered => BUTTON_HOVERED,
+            Interaction::None => BUTTON_NORMAL,
+        };
+    }
+}